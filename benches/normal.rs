@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate criterion;
+extern crate xorshift128plus;
+
+use criterion::{black_box, Criterion};
+use xorshift128plus::XorShift128Plus;
+
+fn normal(c: &mut Criterion) {
+    let mut group = c.benchmark_group("normal");
+
+    group.bench_function("box_muller", |b| {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        b.iter(|| black_box(rng.next_gaussian()))
+    });
+
+    group.bench_function("ziggurat", |b| {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        b.iter(|| black_box(rng.next_normal_ziggurat()))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, normal);
+criterion_main!(benches);
@@ -0,0 +1,326 @@
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::io;
+
+use super::XorShift128Plus;
+
+/// A wrapper that records the state before each draw, so that recent draws can be replayed.
+/// Constructed by [`XorShift128Plus::with_history`](struct.XorShift128Plus.html#method.with_history).
+///
+/// # Examples
+///
+/// ```
+/// extern crate xorshift128plus;
+///
+/// use xorshift128plus::XorShift128Plus;
+///
+/// # fn main() {
+/// let mut rng = XorShift128Plus::from_u32(4293262078).with_history(8);
+///
+/// let first = rng.next();
+/// rng.rewind(1);
+///
+/// assert_eq!(rng.next(), first);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub struct RecordingRng {
+    rng: XorShift128Plus,
+    history: VecDeque<(u64, u64)>,
+    capacity: usize,
+}
+
+/// A stream that alternates between two generators, constructed by
+/// [`XorShift128Plus::interleave`](struct.XorShift128Plus.html#method.interleave).
+pub struct Interleaved {
+    a: XorShift128Plus,
+    b: XorShift128Plus,
+    from_b: bool,
+}
+
+/// A wrapper that logs every float it returns, e.g. to dump the exact sequence a failing run
+/// consumed. Constructed by [`XorShift128Plus::with_log`](struct.XorShift128Plus.html#method.with_log).
+#[cfg(feature = "std")]
+pub struct LoggedRng {
+    rng: XorShift128Plus,
+    log: Vec<f64>,
+}
+
+/// A wrapper that limits how many raw steps may be drawn, constructed by
+/// [`XorShift128Plus::with_budget`](struct.XorShift128Plus.html#method.with_budget).
+pub struct BudgetRng {
+    rng: XorShift128Plus,
+    remaining: u64,
+    exceeded: bool,
+}
+
+impl XorShift128Plus {
+    /// Returns a closure that borrows the generator and calls [`next`](#method.next) each time
+    /// it's called, for passing to APIs that take a `FnMut() -> f64` sampler.
+    pub fn as_sampler (&mut self) -> impl FnMut() -> f64 + '_ {
+        move || self.next()
+    }
+
+    /// Combines two generators into a single stream whose `next` alternately draws from `a` and
+    /// `b`, starting with `a`.
+    pub fn interleave (a: XorShift128Plus, b: XorShift128Plus) -> Interleaved {
+        Interleaved { a, b, from_b: false }
+    }
+
+    /// Wraps the generator in a [`BudgetRng`](struct.BudgetRng.html) that allows at most
+    /// `max_draws` raw steps, e.g. to detect code that consumes more randomness than expected.
+    pub fn with_budget (self, max_draws: u64) -> BudgetRng {
+        BudgetRng { rng: self, remaining: max_draws, exceeded: false }
+    }
+
+    /// Wraps the generator in a [`LoggedRng`](struct.LoggedRng.html) that keeps every value
+    /// returned by its `next`. Note that the log grows without bound.
+    #[cfg(feature = "std")]
+    pub fn with_log (self) -> LoggedRng {
+        LoggedRng { rng: self, log: Vec::new() }
+    }
+
+    /// Wraps the generator in a [`RecordingRng`](struct.RecordingRng.html) that remembers the
+    /// state before each of the last `capacity` draws.
+    #[cfg(feature = "std")]
+    pub fn with_history (self, capacity: usize) -> RecordingRng {
+        RecordingRng { rng: self, history: VecDeque::with_capacity(capacity), capacity }
+    }
+}
+
+#[cfg(feature = "std")]
+impl RecordingRng {
+    /// Returns the next psuedo-random number between 0 (inclusivly) and 1 (exclusivly), see
+    /// [`XorShift128Plus::next`](struct.XorShift128Plus.html#method.next).
+    #[allow(clippy::should_implement_trait)]
+    pub fn next (&mut self) -> f64 {
+        if self.capacity > 0 {
+            if self.history.len() == self.capacity {
+                self.history.pop_front();
+            }
+
+            self.history.push_back((self.rng.s0, self.rng.s1));
+        }
+
+        self.rng.next()
+    }
+
+    /// Restores the state from before the draw `n` draws ago, so that the last `n` values are
+    /// returned again by the following calls to `next`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `n` draws are recorded.
+    pub fn rewind (&mut self, n: usize) {
+        assert!(n <= self.history.len(), "cannot rewind past the recorded history");
+
+        let index = self.history.len() - n;
+
+        if let Some(&(s0, s1)) = self.history.get(index) {
+            self.rng.s0 = s0;
+            self.rng.s1 = s1;
+        }
+
+        self.history.truncate(index);
+    }
+
+    /// Unwraps the generator, discarding the recorded history.
+    pub fn into_inner (self) -> XorShift128Plus {
+        self.rng
+    }
+}
+
+#[cfg(feature = "std")]
+impl LoggedRng {
+    /// Returns the next psuedo-random number between 0 (inclusivly) and 1 (exclusivly), see
+    /// [`XorShift128Plus::next`](struct.XorShift128Plus.html#method.next), and appends it to
+    /// the log.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next (&mut self) -> f64 {
+        let value = self.rng.next();
+        self.log.push(value);
+        value
+    }
+
+    /// Returns every value returned by `next` so far, in order.
+    pub fn log (&self) -> &[f64] {
+        &self.log
+    }
+
+    /// Unwraps the generator, discarding the log.
+    pub fn into_inner (self) -> XorShift128Plus {
+        self.rng
+    }
+}
+
+impl BudgetRng {
+    /// Returns the next psuedo-random number between 0 (inclusivly) and 1 (exclusivly), using
+    /// one step of the budget. Once the budget is used up, it instead always returns 0 without
+    /// advancing the generator, and marks the budget as [`exceeded`](#method.exceeded).
+    #[allow(clippy::should_implement_trait)]
+    pub fn next (&mut self) -> f64 {
+        if self.remaining == 0 {
+            self.exceeded = true;
+            return 0.0;
+        }
+
+        self.remaining -= 1;
+        self.rng.next()
+    }
+
+    /// Returns the number of steps left in the budget.
+    pub fn remaining (&self) -> u64 {
+        self.remaining
+    }
+
+    /// Returns whether `next` has been called after the budget was used up.
+    pub fn exceeded (&self) -> bool {
+        self.exceeded
+    }
+
+    /// Unwraps the generator, discarding the budget.
+    pub fn into_inner (self) -> XorShift128Plus {
+        self.rng
+    }
+}
+
+impl Interleaved {
+    /// Returns the next psuedo-random number between 0 (inclusivly) and 1 (exclusivly), from
+    /// whichever generator is next in turn.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next (&mut self) -> f64 {
+        let rng = if self.from_b { &mut self.b } else { &mut self.a };
+        self.from_b = !self.from_b;
+        rng.next()
+    }
+
+    /// Unwraps the two generators, as `(a, b)`.
+    pub fn into_inner (self) -> (XorShift128Plus, XorShift128Plus) {
+        (self.a, self.b)
+    }
+}
+
+/// Lets the generator be used as an endless reader of psuedo-random bytes, e.g. with
+/// `std::io::copy`. Reads always fill the whole buffer, with the same bytes as
+/// [`fill`](struct.XorShift128Plus.html#method.fill) would, and never fail.
+#[cfg(feature = "std")]
+impl io::Read for XorShift128Plus {
+    fn read (&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.fill_bytes(buf);
+        Ok(buf.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use std::io::Read;
+
+    use super::XorShift128Plus;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_read_random_bytes() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        let mut read = [0u8; 32];
+        let mut filled = [0u8; 32];
+
+        assert_eq!(a.read(&mut read).unwrap(), 32);
+        b.fill_bytes(&mut filled);
+
+        assert_eq!(read, filled);
+        assert!(read.iter().any(|&byte| byte != 0));
+    }
+
+    #[test]
+    fn it_should_enforce_a_budget() {
+        let mut reference = XorShift128Plus::from_u32(4293262078);
+        let mut rng = XorShift128Plus::from_u32(4293262078).with_budget(3);
+
+        for remaining in (0..3).rev() {
+            assert_eq!(rng.next(), reference.next());
+            assert_eq!(rng.remaining(), remaining);
+        }
+
+        assert!(!rng.exceeded());
+        assert_eq!(rng.next(), 0.0);
+        assert!(rng.exceeded());
+
+        assert_eq!(rng.into_inner().next(), reference.next());
+    }
+
+    #[test]
+    fn it_should_expose_a_sampler_closure() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut reference = XorShift128Plus::from_u32(4293262078);
+
+        {
+            let mut sampler = rng.as_sampler();
+
+            for _ in 0..3 {
+                assert_eq!(sampler(), reference.next());
+            }
+        }
+
+        assert_eq!(rng.next(), reference.next());
+    }
+
+    #[test]
+    fn it_should_interleave_generators() {
+        let mut a = XorShift128Plus::from_u32(1);
+        let mut b = XorShift128Plus::from_u32(2);
+        let mut stream = XorShift128Plus::interleave(XorShift128Plus::from_u32(1), XorShift128Plus::from_u32(2));
+
+        for _ in 0..10 {
+            assert_eq!(stream.next(), a.next());
+            assert_eq!(stream.next(), b.next());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_log_returned_values() {
+        let mut rng = XorShift128Plus::from_u32(4293262078).with_log();
+        let values: Vec<f64> = (0..5).map(|_| rng.next()).collect();
+
+        assert_eq!(rng.log(), &values[..]);
+
+        let mut reference = XorShift128Plus::from_u32(4293262078);
+
+        for &value in rng.log() {
+            assert_eq!(value, reference.next());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_replay_recent_draws() {
+        let mut rng = XorShift128Plus::from_u32(4293262078).with_history(3);
+        let values: Vec<f64> = (0..5).map(|_| rng.next()).collect();
+
+        rng.rewind(2);
+
+        assert_eq!(rng.next(), values[3]);
+        assert_eq!(rng.next(), values[4]);
+
+        rng.rewind(3);
+
+        assert_eq!(rng.next(), values[2]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn it_should_not_rewind_past_capacity() {
+        let mut rng = XorShift128Plus::from_u32(4293262078).with_history(3);
+
+        for _ in 0..5 {
+            rng.next();
+        }
+
+        rng.rewind(4);
+    }
+}
@@ -0,0 +1,109 @@
+use super::XorShift128Plus;
+
+impl XorShift128Plus {
+    /// Returns a psuedo-random color as `(red, green, blue)`, each component uniform over all
+    /// byte values. The components are taken from the upper bytes of a single step.
+    pub fn random_rgb (&mut self) -> (u8, u8, u8) {
+        let bits = self.next_u64();
+
+        ((bits >> 56) as u8, (bits >> 48) as u8, (bits >> 40) as u8)
+    }
+
+    /// Returns a psuedo-random color as `(red, green, blue, alpha)`, where the color components
+    /// are uniform bytes as in [`random_rgb`](#method.random_rgb), and the alpha is a uniform
+    /// byte blended towards 255 by `opaque_bias`. Thus a bias of 0 gives a uniform alpha, while a
+    /// bias of 1 always gives an opaque color. All four bytes are taken from a single step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `opaque_bias` is not between 0 and 1 (inclusivly).
+    #[cfg(feature = "std")]
+    pub fn random_rgba (&mut self, opaque_bias: f64) -> (u8, u8, u8, u8) {
+        assert!((0.0..=1.0).contains(&opaque_bias), "opaque_bias must be between 0 and 1");
+
+        let bits = self.next_u64();
+        let alpha = (bits >> 32) as u8 as f64;
+
+        (
+            (bits >> 56) as u8,
+            (bits >> 48) as u8,
+            (bits >> 40) as u8,
+            (alpha + opaque_bias * (255.0 - alpha)).round() as u8,
+        )
+    }
+
+    /// Returns a psuedo-random color as `(hue, saturation, lightness)`, with the hue in degrees
+    /// between 0 (inclusivly) and 360 (exclusivly), and the saturation and lightness between 0
+    /// (inclusivly) and 1 (exclusivly).
+    pub fn random_hsl (&mut self) -> (f64, f64, f64) {
+        let hue = self.next() * 360.0;
+        let saturation = self.next();
+        let lightness = self.next();
+
+        (hue, saturation, lightness)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XorShift128Plus;
+
+    #[test]
+    fn it_should_generate_rgb_colors() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+        let mut seen = [false; 256];
+
+        for _ in 0..10_000 {
+            let (r, g, blue) = a.random_rgb();
+
+            seen[r as usize] = true;
+            seen[g as usize] = true;
+            seen[blue as usize] = true;
+
+            assert_eq!((r, g, blue), b.random_rgb());
+        }
+
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_generate_rgba_colors() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut reference = XorShift128Plus::from_u32(4293262078);
+        let mut previous_mean = 0.0;
+
+        for &bias in [0.0, 0.5, 0.9].iter() {
+            let mut sum = 0.0;
+
+            for _ in 0..10_000 {
+                let (r, g, b, alpha) = rng.random_rgba(bias);
+
+                assert_eq!((r, g, b), reference.random_rgb());
+                sum += alpha as f64;
+            }
+
+            assert!(sum / 10_000.0 > previous_mean);
+            previous_mean = sum / 10_000.0;
+        }
+
+        assert_eq!(rng.random_rgba(1.0).3, 255);
+    }
+
+    #[test]
+    fn it_should_generate_hsl_colors() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        for _ in 0..10_000 {
+            let (h, s, l) = a.random_hsl();
+
+            assert!((0.0..360.0).contains(&h));
+            assert!((0.0..1.0).contains(&s));
+            assert!((0.0..1.0).contains(&l));
+
+            assert_eq!((h, s, l), b.random_hsl());
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use super::XorShift128Plus;
+
+const SELF_TEST_SAMPLES: usize = 100_000;
+const SELF_TEST_BUCKETS: usize = 16;
+
+const REFERENCE_BYTES_SEED: [u8; 16] = [
+    0x5d, 0x28, 0x94, 0x50, 0xc8, 0x88, 0xf9, 0x9b,
+    0x5e, 0x5c, 0x1f, 0xd1, 0x35, 0x09, 0xe3, 0x9e,
+];
+const REFERENCE_BYTES_SEQUENCE: [f64; 5] = [
+    0.35873106038177727,
+    0.7433543130711686,
+    0.6325316214071923,
+    0.708663591569944,
+    0.8974382234842848,
+];
+const REFERENCE_U32_SEED: u32 = 4293262078;
+const REFERENCE_U32_SEQUENCE: [f64; 5] = [
+    0.4335893835472515,
+    0.6067907909036327,
+    0.046905965279849804,
+    0.480991995797152,
+    0.6796126170804464,
+];
+
+/// The version of the output contract of [`XorShift128Plus`](struct.XorShift128Plus.html),
+/// i.e. the exact values it produces for a given seed. Any change that alters the output of
+/// seeding or of [`next`](struct.XorShift128Plus.html#method.next) bumps this version, so that
+/// cross-checks against other implementations can tell which outputs to expect.
+pub const ALGORITHM_VERSION: u32 = 1;
+
+/// A running mean and variance of a stream of numbers, updated in constant memory with
+/// Welford's algorithm, e.g. for validating a distribution inline with
+/// [`XorShift128Plus::next_tracked`](struct.XorShift128Plus.html#method.next_tracked).
+#[derive(Clone, Debug, Default)]
+pub struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    /// Constructs a new, empty accumulator.
+    pub fn new () -> RunningStats {
+        RunningStats::default()
+    }
+
+    /// Adds `x` to the statistics.
+    pub fn push (&mut self, x: f64) {
+        self.count += 1;
+
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    /// Returns the number of values pushed so far.
+    pub fn count (&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean of the values pushed so far, or 0 if there are none.
+    pub fn mean (&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the sample variance of the values pushed so far, or 0 if there are fewer than
+    /// two.
+    pub fn variance (&self) -> f64 {
+        if self.count < 2 { 0.0 } else { self.m2 / (self.count - 1) as f64 }
+    }
+}
+
+impl XorShift128Plus {
+    /// Returns the next psuedo-random number between 0 (inclusivly) and 1 (exclusivly), like
+    /// [`next`](#method.next), and pushes it onto `stats`.
+    pub fn next_tracked (&mut self, stats: &mut RunningStats) -> f64 {
+        let value = self.next();
+        stats.push(value);
+        value
+    }
+
+    /// Returns the first `count` values of the canonical float sequence for `seed`, i.e. what
+    /// [`from_bytes`](#method.from_bytes) followed by repeated calls to [`next`](#method.next)
+    /// returns. The sequence matches the JavaScript implementation of xorshift128+ this crate is
+    /// modelled after, so it's useful for verifying ports in other languages.
+    #[cfg(feature = "std")]
+    pub fn expected_sequence (seed: [u8; 16], count: usize) -> Vec<f64> {
+        let mut rng = XorShift128Plus::from_bytes(seed);
+        (0..count).map(|_| rng.next()).collect()
+    }
+
+    /// Returns whether this build produces the reference outputs of
+    /// [`ALGORITHM_VERSION`](constant.ALGORITHM_VERSION.html), checking the first five values of
+    /// [`expected_sequence`](#method.expected_sequence) for the canonical bytes seed, and of
+    /// [`from_u32`](#method.from_u32) for the seed used in the readme.
+    pub fn verify_reference () -> bool {
+        let mut from_bytes = XorShift128Plus::from_bytes(REFERENCE_BYTES_SEED);
+        let mut from_u32 = XorShift128Plus::from_u32(REFERENCE_U32_SEED);
+
+        REFERENCE_BYTES_SEQUENCE.iter().all(|&value| from_bytes.next() == value) &&
+        REFERENCE_U32_SEQUENCE.iter().all(|&value| from_u32.next() == value)
+    }
+
+    /// Runs a cheap statistical smoke test on a generator seeded with `seed`, e.g. to sanity
+    /// check a state loaded from disk. It draws 100 000 values and checks that their mean is
+    /// within 0.01 of 0.5, and that each of 16 equal buckets of the unit interval received
+    /// within 10% of its expected share. Both thresholds are many standard deviations wide, so a
+    /// working generator practically never fails, while a broken one (e.g. an all-zero seed)
+    /// always does.
+    pub fn self_test (seed: [u8; 16]) -> bool {
+        let mut rng = XorShift128Plus::from_bytes(seed);
+        let mut buckets = [0usize; SELF_TEST_BUCKETS];
+        let mut sum = 0.0;
+
+        for _ in 0..SELF_TEST_SAMPLES {
+            let value = rng.next();
+
+            sum += value;
+            buckets[(value * SELF_TEST_BUCKETS as f64) as usize] += 1;
+        }
+
+        let mean = sum / SELF_TEST_SAMPLES as f64;
+        let expected = SELF_TEST_SAMPLES / SELF_TEST_BUCKETS;
+
+        (mean - 0.5).abs() < 0.01 && buckets.iter().all(|&count| count.abs_diff(expected) < expected / 10)
+    }
+
+    /// Draws `samples` values from a generator seeded with `seed` and returns their lag-1
+    /// autocorrelation coefficient, i.e. how well each value predicts the next one. It should be
+    /// close to 0 for a good generator, within a few multiples of `1 / sqrt(samples)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is less than 2.
+    #[cfg(feature = "std")]
+    pub fn lag1_autocorrelation (seed: [u8; 16], samples: usize) -> f64 {
+        assert!(samples >= 2, "samples must be at least 2");
+
+        let values = XorShift128Plus::expected_sequence(seed, samples);
+        let mean = values.iter().sum::<f64>() / samples as f64;
+
+        let numerator: f64 = values.windows(2).map(|pair| (pair[0] - mean) * (pair[1] - mean)).sum();
+        let denominator: f64 = values.iter().map(|value| (value - mean) * (value - mean)).sum();
+
+        numerator / denominator
+    }
+
+    /// Returns the number of raw steps after which this generator reaches the state of `target`,
+    /// checking at most `max_steps` steps ahead, or `None` if it isn't reached within them. This
+    /// is a diagnostic for confirming that two substreams don't collide within a window, and
+    /// takes time proportional to `max_steps`.
+    pub fn steps_until (&self, target: &XorShift128Plus, max_steps: u64) -> Option<u64> {
+        let mut rng = XorShift128Plus::from_raw(self.s0, self.s1);
+
+        for steps in 0..=max_steps {
+            if rng.s0 == target.s0 && rng.s1 == target.s1 {
+                return Some(steps);
+            }
+
+            rng.next_u64();
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RunningStats, XorShift128Plus, ALGORITHM_VERSION, REFERENCE_BYTES_SEED};
+
+    #[test]
+    fn it_should_pass_the_self_test() {
+        assert!(XorShift128Plus::self_test(REFERENCE_BYTES_SEED));
+    }
+
+    #[test]
+    fn it_should_fail_the_self_test_with_a_zero_seed() {
+        assert!(!XorShift128Plus::self_test([0; 16]));
+    }
+
+    #[test]
+    fn it_should_count_steps_until_a_state() {
+        let rng = XorShift128Plus::from_u32(4293262078);
+        let mut advanced = rng.clone();
+
+        advanced.discard(3);
+
+        assert_eq!(rng.steps_until(&advanced, 10), Some(3));
+        assert_eq!(rng.steps_until(&advanced, 3), Some(3));
+        assert_eq!(rng.steps_until(&advanced, 2), None);
+        assert_eq!(rng.steps_until(&rng, 0), Some(0));
+        assert_eq!(advanced.steps_until(&rng, 1000), None);
+    }
+
+    #[test]
+    fn it_should_verify_the_reference_outputs() {
+        assert_eq!(ALGORITHM_VERSION, 1);
+        assert!(XorShift128Plus::verify_reference());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_return_the_expected_sequence() {
+        let sequence = XorShift128Plus::expected_sequence(REFERENCE_BYTES_SEED, 5);
+
+        assert_eq!(sequence, [
+            0.35873106038177727,
+            0.7433543130711686,
+            0.6325316214071923,
+            0.708663591569944,
+            0.8974382234842848,
+        ]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_have_low_autocorrelation() {
+        let correlation = XorShift128Plus::lag1_autocorrelation(REFERENCE_BYTES_SEED, 100_000);
+
+        assert!(correlation.abs() < 0.01);
+    }
+
+    #[test]
+    fn it_should_track_running_statistics() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut stats = RunningStats::new();
+
+        for _ in 0..100_000 {
+            rng.next_tracked(&mut stats);
+        }
+
+        assert_eq!(stats.count(), 100_000);
+        assert!((stats.mean() - 0.5).abs() < 0.01);
+        assert!((stats.variance() - 1.0 / 12.0).abs() < 0.001);
+
+        let mut stats = RunningStats::new();
+
+        for &x in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].iter() {
+            stats.push(x);
+        }
+
+        assert_eq!(stats.mean(), 5.0);
+        assert!((stats.variance() - 32.0 / 7.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,1083 @@
+use std::f64::consts::PI;
+
+use super::{XorShift128Plus, MANTISSA_MASK};
+use ziggurat_tables::{ZIG_NORM_F, ZIG_NORM_R, ZIG_NORM_X};
+
+/// The maximum number of times [`roll_exploding`](struct.XorShift128Plus.html#method.roll_exploding)
+/// rolls again after a max face.
+const MAX_EXPLOSIONS: usize = 100;
+/// The maximum number of proposals [`rejection_sample`](struct.XorShift128Plus.html#method.rejection_sample)
+/// draws before giving up.
+const MAX_REJECTIONS: usize = 1_000_000;
+
+/// A sampler for the Zipf distribution over `1..=n`, where `k` is drawn with probability
+/// proportional to `1 / k^exponent`. The constants of the rejection-inversion method (W.
+/// Hörmann and G. Derflinger, "Rejection-inversion to generate variates from monotone discrete
+/// distributions") are computed once up front, so sampling takes constant time and memory
+/// regardless of `n`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate xorshift128plus;
+///
+/// use xorshift128plus::{XorShift128Plus, Zipf};
+///
+/// # fn main() {
+/// let mut rng = XorShift128Plus::from_u32(4293262078);
+/// let zipf = Zipf::new(1000, 1.0);
+///
+/// println!("Accessed page: {}", zipf.sample(&mut rng));
+/// # }
+/// ```
+pub struct Zipf {
+    n: u64,
+    exponent: f64,
+    h_integral_x1: f64,
+    h_integral_n: f64,
+    s: f64,
+}
+
+impl Zipf {
+    /// Constructs a new sampler over `1..=n` with the given exponent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0, or if `exponent` is not greater than 0.
+    pub fn new (n: u64, exponent: f64) -> Zipf {
+        assert!(n > 0, "n must be greater than 0");
+        assert!(exponent > 0.0, "exponent must be greater than 0");
+
+        let mut zipf = Zipf { n, exponent, h_integral_x1: 0.0, h_integral_n: 0.0, s: 0.0 };
+
+        zipf.h_integral_x1 = zipf.h_integral(1.5) - 1.0;
+        zipf.h_integral_n = zipf.h_integral(n as f64 + 0.5);
+        zipf.s = 2.0 - zipf.h_integral_inverse(zipf.h_integral(2.5) - zipf.h(2.0));
+
+        zipf
+    }
+
+    /// Returns a psuedo-random integer between 1 and `n` (both inclusivly).
+    pub fn sample (&self, rng: &mut XorShift128Plus) -> u64 {
+        loop {
+            let u = self.h_integral_n + rng.next() * (self.h_integral_x1 - self.h_integral_n);
+            let x = self.h_integral_inverse(u);
+            let k = (x + 0.5).floor().max(1.0).min(self.n as f64);
+
+            if k - x <= self.s || u >= self.h_integral(k + 0.5) - self.h(k) {
+                return k as u64;
+            }
+        }
+    }
+
+    fn h (&self, x: f64) -> f64 {
+        (-self.exponent * x.ln()).exp()
+    }
+
+    fn h_integral (&self, x: f64) -> f64 {
+        let log_x = x.ln();
+        helper2((1.0 - self.exponent) * log_x) * log_x
+    }
+
+    fn h_integral_inverse (&self, x: f64) -> f64 {
+        let t = (x * (1.0 - self.exponent)).max(-1.0);
+        (helper1(t) * x).exp()
+    }
+}
+
+// Returns the natural logarithm of the gamma function for `x > 0`, using a Stirling series
+// after shifting small arguments up.
+fn ln_gamma (x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 10] = [
+        8.333333333333333e-02, -2.777777777777778e-03, 7.936507936507937e-04,
+        -5.952380952380952e-04, 8.417508417508418e-04, -1.917526917526918e-03,
+        6.41025641025641e-03, -2.955065359477124e-02, 1.796443723688307e-01,
+        -1.39243221690590e+00,
+    ];
+
+    if x == 1.0 || x == 2.0 {
+        return 0.0;
+    }
+
+    let shift = if x <= 7.0 { (7.0 - x).floor() } else { 0.0 };
+    let mut x0 = x + shift;
+
+    let x2 = 1.0 / (x0 * x0);
+    let mut series = COEFFICIENTS[9];
+
+    for &coefficient in COEFFICIENTS[..9].iter().rev() {
+        series = series * x2 + coefficient;
+    }
+
+    let mut result = series / x0 + 0.5 * (2.0 * PI).ln() + (x0 - 0.5) * x0.ln() - x0;
+
+    for _ in 0..shift as u32 {
+        x0 -= 1.0;
+        result -= x0.ln();
+    }
+
+    result
+}
+
+// Returns `ln(1 + x) / x`, using a Taylor series close to 0.
+fn helper1 (x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        x.ln_1p() / x
+    } else {
+        1.0 - x * (0.5 - x * (1.0 / 3.0 - x * 0.25))
+    }
+}
+
+// Returns `(e^x - 1) / x`, using a Taylor series close to 0.
+fn helper2 (x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        x.exp_m1() / x
+    } else {
+        1.0 + x * 0.5 * (1.0 + x * (1.0 / 3.0) * (1.0 + x * 0.25))
+    }
+}
+
+impl XorShift128Plus {
+    /// Returns a psuedo-random number from the standard normal distribution, using the
+    /// Box-Muller transform. Only one of the two values the transform produces is used, so no
+    /// state is kept between calls. See also
+    /// [`next_normal_ziggurat`](#method.next_normal_ziggurat), which is faster.
+    pub fn next_gaussian (&mut self) -> f64 {
+        let u1 = 1.0 - self.next();
+        let u2 = self.next();
+
+        (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+
+    /// Returns a psuedo-random number from the standard normal distribution, using the Ziggurat
+    /// method of Marsaglia and Tsang with 256 precomputed layers. Most draws take a single step
+    /// and no transcendental functions, which makes it several times faster than
+    /// [`next_gaussian`](#method.next_gaussian), though the two return different sequences.
+    pub fn next_normal_ziggurat (&mut self) -> f64 {
+        loop {
+            let bits = self.next_u64();
+
+            // The top byte picks the layer, and the 52 bits below it give a value in [-1, 1)
+            let i = (bits >> 56) as usize;
+            let u = (((bits >> 4) & MANTISSA_MASK) as f64) / ((MANTISSA_MASK + 1) as f64) * 2.0 - 1.0;
+            let x = u * ZIG_NORM_X[i];
+
+            if x.abs() < ZIG_NORM_X[i + 1] {
+                return x;
+            }
+
+            if i == 0 {
+                return self.normal_tail(u < 0.0);
+            }
+
+            if ZIG_NORM_F[i + 1] + (ZIG_NORM_F[i] - ZIG_NORM_F[i + 1]) * self.next() < (-0.5 * x * x).exp() {
+                return x;
+            }
+        }
+    }
+
+    // Samples the tail of the normal distribution beyond `ZIG_NORM_R`, using Marsaglia's method.
+    fn normal_tail (&mut self, negative: bool) -> f64 {
+        loop {
+            let x = self.next_open().ln() / ZIG_NORM_R;
+            let y = self.next_open().ln();
+
+            if -2.0 * y >= x * x {
+                return if negative { x - ZIG_NORM_R } else { ZIG_NORM_R - x };
+            }
+        }
+    }
+
+    // Returns a gamma distributed number with the given shape and scale, using the method of
+    // Marsaglia and Tsang, boosting shapes below 1 as they describe.
+    fn next_gamma (&mut self, shape: f64, scale: f64) -> f64 {
+        if shape < 1.0 {
+            return self.next_gamma(shape + 1.0, scale) * self.next_open().powf(1.0 / shape);
+        }
+
+        let d = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+
+        loop {
+            let x = self.next_normal_ziggurat();
+            let v = 1.0 + c * x;
+
+            if v <= 0.0 {
+                continue;
+            }
+
+            let v = v * v * v;
+
+            if self.next_open().ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+                return d * v * scale;
+            }
+        }
+    }
+
+    // Returns a Poisson distributed integer with the given mean, by multiplying uniforms for
+    // small means and with Hörmann's transformed rejection method (PTRS) for large ones.
+    fn next_poisson (&mut self, lambda: f64) -> u64 {
+        if lambda < 10.0 {
+            let limit = (-lambda).exp();
+            let mut product = self.next();
+            let mut k = 0;
+
+            while product > limit {
+                product *= self.next();
+                k += 1;
+            }
+
+            return k;
+        }
+
+        let log_lambda = lambda.ln();
+        let b = 0.931 + 2.53 * lambda.sqrt();
+        let a = -0.059 + 0.02483 * b;
+        let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+        let vr = 0.9277 - 3.6224 / (b - 2.0);
+
+        loop {
+            let u = self.next() - 0.5;
+            let v = self.next_open();
+            let us = 0.5 - u.abs();
+            let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+
+            if us >= 0.07 && v <= vr {
+                return k as u64;
+            }
+
+            if k < 0.0 || (us < 0.013 && v > us) {
+                continue;
+            }
+
+            let log_density = -lambda + k * log_lambda - ln_gamma(k + 1.0);
+
+            if v.ln() + inv_alpha.ln() - (a / (us * us) + b).ln() <= log_density {
+                return k as u64;
+            }
+        }
+    }
+
+    /// Returns a psuedo-random integer from the negative binomial distribution, i.e. the number
+    /// of failures before `r` successes when each trial succeeds with probability `p`. It's drawn
+    /// as a Poisson distribution whose mean is itself drawn from a gamma distribution, which also
+    /// allows `r` to be fractional. The mean is `r * (1 - p) / p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r` is not greater than 0, or if `p` is not greater than 0 and at most 1.
+    pub fn next_negative_binomial (&mut self, r: f64, p: f64) -> u64 {
+        assert!(r > 0.0, "r must be greater than 0");
+        assert!(p > 0.0 && p <= 1.0, "p must be greater than 0 and at most 1");
+
+        if p == 1.0 {
+            return 0;
+        }
+
+        let lambda = self.next_gamma(r, (1.0 - p) / p);
+        self.next_poisson(lambda)
+    }
+
+    /// Returns a psuedo-random integer from the hypergeometric distribution, i.e. the number of
+    /// successes among `draws` items drawn without replacement from `population` items, of
+    /// which `successes` are successes. The items are drawn one at a time with unbiased integer
+    /// draws, so it takes time proportional to `min(draws, population - draws)`. The mean is
+    /// `draws * successes / population`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `successes` or `draws` is greater than `population`.
+    pub fn next_hypergeometric (&mut self, population: u64, successes: u64, draws: u64) -> u64 {
+        assert!(successes <= population, "successes must not be greater than population");
+        assert!(draws <= population, "draws must not be greater than population");
+
+        // Drawing more than half the population is the same as leaving the rest undrawn
+        let sampled = draws.min(population - draws);
+        let mut remaining = population;
+        let mut remaining_successes = successes;
+
+        for _ in 0..sampled {
+            if self.next_below(remaining) < remaining_successes {
+                remaining_successes -= 1;
+            }
+
+            remaining -= 1;
+        }
+
+        let drawn_successes = successes - remaining_successes;
+
+        if sampled == draws { drawn_successes } else { remaining_successes }
+    }
+
+    /// Returns a pair of psuedo-random normally distributed numbers with the given means and
+    /// standard deviations, and a correlation of `corr` between them. The pair is made by
+    /// applying the Cholesky factor of the covariance matrix to two independent standard normals.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `corr` is not between -1 and 1 (inclusivly).
+    pub fn next_bivariate_normal (&mut self, mean: (f64, f64), std: (f64, f64), corr: f64) -> (f64, f64) {
+        assert!((-1.0..=1.0).contains(&corr), "corr must be between -1 and 1");
+
+        let z0 = self.next_gaussian();
+        let z1 = self.next_gaussian();
+
+        (
+            mean.0 + std.0 * z0,
+            mean.1 + std.1 * (corr * z0 + (1.0 - corr * corr).sqrt() * z1),
+        )
+    }
+
+    /// Returns a psuedo-random number from a mixture of normal distributions, where each
+    /// component is given as `(weight, mean, std)`. A component is first chosen proportionally
+    /// to its weight, and the number is then drawn from that component's normal distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `components` is empty, if any weight is negative or not finite, or if all
+    /// weights are 0.
+    pub fn next_gaussian_mixture (&mut self, components: &[(f64, f64, f64)]) -> f64 {
+        let (_, mean, std) = components[self.weighted_index_by(components.len(), |i| components[i].0)];
+
+        mean + std * self.next_gaussian()
+    }
+
+    /// Returns a psuedo-random number from a normal distribution with the given mean and standard
+    /// deviation, truncated to the interval from `low` to `high` (both inclusivly). It draws from
+    /// the normal distribution until a value lands in the interval, so intervals that are narrow
+    /// and far from the mean may take a very long time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is not less than `high`.
+    pub fn next_truncated_normal (&mut self, mean: f64, std: f64, low: f64, high: f64) -> f64 {
+        assert!(low < high, "low must be less than high");
+
+        loop {
+            let value = mean + std * self.next_gaussian();
+
+            if (low..=high).contains(&value) {
+                return value;
+            }
+        }
+    }
+
+    /// Returns a psuedo-random number chosen uniformly among `start`, `start + step`,
+    /// `start + 2 * step` and so on, up to but not including `stop`. The number of steps is
+    /// computed up front and the step is picked with an unbiased integer draw.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not greater than 0, or if `stop` is not greater than `start`.
+    pub fn next_stepped (&mut self, start: f64, stop: f64, step: f64) -> f64 {
+        assert!(step > 0.0, "step must be greater than 0");
+        assert!(stop > start, "stop must be greater than start");
+
+        let mut steps = ((stop - start) / step).ceil() as u64;
+
+        // The division can round up past a whole number of steps, which would make the last
+        // step land on `stop`
+        while start + (steps - 1) as f64 * step >= stop {
+            steps -= 1;
+        }
+
+        start + (self.next_below(steps) as f64) * step
+    }
+
+    /// Returns a psuedo-random number from a histogram, where bin `i` spans from `edges[i]`
+    /// (inclusivly) to `edges[i + 1]` (exclusivly) and holds `counts[i]` samples. A bin is chosen
+    /// proportionally to its count with an unbiased integer draw, and the number is then drawn
+    /// uniformly within the bin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `edges` doesn't have exactly one element more than `counts`, if all counts are
+    /// 0, or if their sum overflows a `u64`.
+    pub fn sample_histogram (&mut self, edges: &[f64], counts: &[u64]) -> f64 {
+        assert_eq!(edges.len(), counts.len() + 1, "edges must have one element more than counts");
+
+        let total = counts.iter()
+            .try_fold(0u64, |sum, &count| sum.checked_add(count))
+            .expect("the sum of counts must fit in a u64");
+
+        assert!(total > 0, "at least one count must be greater than 0");
+
+        let mut target = self.next_below(total);
+        let mut bin = 0;
+
+        while target >= counts[bin] {
+            target -= counts[bin];
+            bin += 1;
+        }
+
+        edges[bin] + self.next() * (edges[bin + 1] - edges[bin])
+    }
+
+    /// Returns a psuedo-random number from the piecewise-linear density through the points
+    /// `(x[i], y[i])`, which doesn't need to be normalized. The CDF is built by integrating each
+    /// segment with the trapezoidal rule, a segment is chosen proportionally to its area, and
+    /// the quadratic CDF within it is inverted exactly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` and `y` differ in length or have fewer than 2 points, if `x` isn't strictly
+    /// increasing, if any `y` is negative or not finite, or if the total area is 0.
+    pub fn sample_piecewise_linear (&mut self, x: &[f64], y: &[f64]) -> f64 {
+        assert_eq!(x.len(), y.len(), "x and y must have the same length");
+        assert!(x.len() >= 2, "at least 2 points are required");
+        assert!(x.windows(2).all(|pair| pair[0] < pair[1]), "x must be strictly increasing");
+        assert!(y.iter().all(|&y| y >= 0.0 && y.is_finite()), "y must be non-negative and finite");
+
+        let areas = (0..x.len() - 1)
+            .map(|i| (y[i] + y[i + 1]) / 2.0 * (x[i + 1] - x[i]))
+            .collect::<Vec<_>>();
+        let total = areas.iter().sum::<f64>();
+
+        assert!(total > 0.0, "the total area must be greater than 0");
+
+        let mut target = self.next() * total;
+        let mut segment = 0;
+
+        // Rounding errors can leave `target` just above the final area, so stop at the last
+        // segment with any area
+        while target >= areas[segment] && areas[segment + 1..].iter().any(|&area| area > 0.0) {
+            target -= areas[segment];
+            segment += 1;
+        }
+
+        // Solves `y0 * t + slope * t² / 2 = target` for the offset `t` into the segment, in a
+        // form that stays stable when either the slope or `y0` is 0
+        let width = x[segment + 1] - x[segment];
+        let slope = (y[segment + 1] - y[segment]) / width;
+        let denominator = y[segment] + (y[segment] * y[segment] + 2.0 * slope * target).max(0.0).sqrt();
+        let t = if denominator > 0.0 { 2.0 * target / denominator } else { 0.0 };
+
+        x[segment] + t.min(width)
+    }
+
+    /// Returns a psuedo-random number from the exponential distribution with rate `lambda`,
+    /// i.e. with mean `1 / lambda`, using the inverse CDF. The uniform draw comes from
+    /// [`next_open`](#method.next_open), so the result is always finite and greater than 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lambda` is not greater than 0.
+    pub fn next_exp (&mut self, lambda: f64) -> f64 {
+        assert!(lambda > 0.0, "lambda must be greater than 0");
+
+        -self.next_open().ln() / lambda
+    }
+
+    /// Returns a psuedo-random number from the Laplace (double exponential) distribution with
+    /// the given location and scale, using the inverse CDF. The uniform draw comes from
+    /// [`next_open`](#method.next_open), so the result is always finite.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is not greater than 0.
+    pub fn next_laplace (&mut self, location: f64, scale: f64) -> f64 {
+        assert!(scale > 0.0, "scale must be greater than 0");
+
+        let u = self.next_open() - 0.5;
+
+        location - scale * u.signum() * (1.0 - 2.0 * u.abs()).ln()
+    }
+
+    /// Returns a psuedo-random number between 0 (inclusivly) and 1 (exclusivly) from the arcsine
+    /// distribution, i.e. Beta(0.5, 0.5), computed as `sin²(π/2 · next())`. Its density is
+    /// U-shaped, so values cluster near 0 and 1 rather than in the middle.
+    pub fn next_arcsine (&mut self) -> f64 {
+        let value = (PI / 2.0 * self.next()).sin().powi(2);
+
+        // Draws just below 1 would otherwise round up to exactly 1
+        value.min(1.0f64.next_down())
+    }
+
+    /// Returns a psuedo-random number from the Gumbel distribution with the given location and
+    /// scale, using the inverse CDF `location - scale * ln(-ln(u))`. The uniform draw comes from
+    /// [`next_open`](#method.next_open), so both logarithms are always finite.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is not greater than 0.
+    pub fn next_gumbel (&mut self, location: f64, scale: f64) -> f64 {
+        assert!(scale > 0.0, "scale must be greater than 0");
+
+        location - scale * (-self.next_open().ln()).ln()
+    }
+
+    /// Returns a psuedo-random angle in radians from the von Mises (circular normal)
+    /// distribution, between -π (exclusivly) and π (inclusivly), concentrated around `mu` with
+    /// concentration `kappa`. It uses the rejection method of Best and Fisher, and a `kappa` of
+    /// 0 gives a uniform angle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `kappa` is negative or not finite.
+    pub fn next_von_mises (&mut self, mu: f64, kappa: f64) -> f64 {
+        assert!(kappa >= 0.0 && kappa.is_finite(), "kappa must be non-negative and finite");
+
+        if kappa == 0.0 {
+            return PI - 2.0 * PI * self.next();
+        }
+
+        let tau = 1.0 + (1.0 + 4.0 * kappa * kappa).sqrt();
+        let rho = (tau - (2.0 * tau).sqrt()) / (2.0 * kappa);
+        let r = (1.0 + rho * rho) / (2.0 * rho);
+
+        let theta = loop {
+            let z = (PI * self.next()).cos();
+            let f = (1.0 + r * z) / (r + z);
+            let c = kappa * (r - f);
+            let u = self.next_open();
+
+            if c * (2.0 - c) > u || (c / u).ln() + 1.0 - c >= 0.0 {
+                let angle = f.clamp(-1.0, 1.0).acos();
+                break if self.random_sign() < 0.0 { -angle } else { angle };
+            }
+        };
+
+        // Wraps the angle back into (-π, π]
+        let wrapped = (mu + theta + PI).rem_euclid(2.0 * PI) - PI;
+
+        if wrapped <= -PI { PI } else { wrapped }
+    }
+
+    /// Returns a psuedo-random level between 0 and `max_level` (both inclusivly), where reaching
+    /// each successive level takes another success with probability `p`, e.g. for rarity tiers.
+    /// Thus level `L` below `max_level` occurs with probability `p^L * (1 - p)`, and `max_level`
+    /// takes up the remaining probability.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not between 0 and 1 (inclusivly).
+    pub fn next_level (&mut self, p: f64, max_level: u32) -> u32 {
+        assert!((0.0..=1.0).contains(&p), "p must be between 0 and 1");
+
+        let mut level = 0;
+
+        while level < max_level && self.next() < p {
+            level += 1;
+        }
+
+        level
+    }
+
+    /// Returns `true` with probability `wins / (wins + losses)`, i.e. with odds of `wins` to
+    /// `losses`, using a single unbiased integer draw.
+    ///
+    /// # Panics
+    ///
+    /// Panics if both `wins` and `losses` are 0, or if their sum overflows a `u64`.
+    pub fn gen_odds (&mut self, wins: u64, losses: u64) -> bool {
+        let total = wins.checked_add(losses).expect("wins + losses must fit in a u64");
+
+        assert!(total > 0, "wins + losses must be greater than 0");
+
+        self.next_below(total) < wins
+    }
+
+    /// Returns the total of a psuedo-random exploding die with faces `1..=sides`, where every
+    /// max face adds another roll, repeating until a non-max face comes up. At most 100 extra
+    /// rolls are made, which only matters for very small dice, e.g. a one-sided die always
+    /// returns 101.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sides` is 0.
+    pub fn roll_exploding (&mut self, sides: u64) -> u64 {
+        assert!(sides > 0, "sides must be greater than 0");
+
+        let mut total = 0u64;
+
+        for _ in 0..=MAX_EXPLOSIONS {
+            let roll = self.next_below(sides) + 1;
+            total = total.saturating_add(roll);
+
+            if roll != sides {
+                break;
+            }
+        }
+
+        total
+    }
+
+    /// Returns a psuedo-random number from the distribution with the given quantile function
+    /// (i.e. inverse CDF), by passing it a uniform draw from [`next_open`](#method.next_open).
+    /// Since the draw is never exactly 0 or 1, the quantile function doesn't need to handle the
+    /// endpoints, where it's often infinite.
+    pub fn sample_inverse_cdf<F: Fn(f64) -> f64> (&mut self, quantile: F) -> f64 {
+        quantile(self.next_open())
+    }
+
+    /// Returns the first value drawn by `propose` that `accept` returns `true` for, i.e. a
+    /// psuedo-random number from the proposal distribution conditioned on acceptance. This is
+    /// the core loop of rejection sampling, where `accept` usually compares the target density
+    /// against a scaled proposal density using another uniform draw.
+    ///
+    /// # Panics
+    ///
+    /// Panics if 1 000 000 proposals in a row are rejected, which almost always means that the
+    /// acceptance condition can't be met.
+    pub fn rejection_sample<F, G> (&mut self, mut propose: F, mut accept: G) -> f64
+        where F: FnMut(&mut Self) -> f64, G: FnMut(f64) -> bool
+    {
+        for _ in 0..MAX_REJECTIONS {
+            let value = propose(self);
+
+            if accept(value) {
+                return value;
+            }
+        }
+
+        panic!("rejected {} proposals in a row", MAX_REJECTIONS);
+    }
+
+    /// Returns a psuedo-random integer between 1 and `n` (both inclusivly) following Zipf's law
+    /// with the given exponent. When drawing many values with the same parameters, construct a
+    /// [`Zipf`](struct.Zipf.html) once instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0, or if `exponent` is not greater than 0.
+    pub fn next_zipf (&mut self, n: u64, exponent: f64) -> u64 {
+        Zipf::new(n, exponent).sample(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::{XorShift128Plus, Zipf};
+
+    #[test]
+    fn it_should_generate_correlated_normals() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let samples: Vec<(f64, f64)> = (0..20_000)
+            .map(|_| rng.next_bivariate_normal((1.0, -2.0), (2.0, 0.5), 0.7))
+            .collect();
+
+        let n = samples.len() as f64;
+        let mean0 = samples.iter().map(|s| s.0).sum::<f64>() / n;
+        let mean1 = samples.iter().map(|s| s.1).sum::<f64>() / n;
+
+        let cov = samples.iter().map(|s| (s.0 - mean0) * (s.1 - mean1)).sum::<f64>() / n;
+        let var0 = samples.iter().map(|s| (s.0 - mean0).powi(2)).sum::<f64>() / n;
+        let var1 = samples.iter().map(|s| (s.1 - mean1).powi(2)).sum::<f64>() / n;
+
+        assert!((mean0 - 1.0).abs() < 0.05);
+        assert!((mean1 + 2.0).abs() < 0.05);
+        assert!((var0.sqrt() - 2.0).abs() < 0.05);
+        assert!((var1.sqrt() - 0.5).abs() < 0.05);
+        assert!((cov / (var0 * var1).sqrt() - 0.7).abs() < 0.02);
+    }
+
+    #[test]
+    fn it_should_generate_gaussian_mixtures() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let components = [(3.0, -10.0, 1.0), (1.0, 10.0, 1.0)];
+
+        let mut low = Vec::new();
+        let mut high = Vec::new();
+
+        for _ in 0..10_000 {
+            let value = rng.next_gaussian_mixture(&components);
+
+            assert!(value.abs() > 3.0);
+
+            if value < 0.0 { low.push(value) } else { high.push(value) }
+        }
+
+        assert!(low.len() > 7_300 && low.len() < 7_700);
+        assert!((low.iter().sum::<f64>() / low.len() as f64 + 10.0).abs() < 0.1);
+        assert!((high.iter().sum::<f64>() / high.len() as f64 - 10.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn it_should_generate_zipf_distributed_integers() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let zipf = Zipf::new(10, 1.0);
+        let mut counts = [0u32; 11];
+
+        for _ in 0..100_000 {
+            let k = zipf.sample(&mut rng);
+
+            assert!((1..=10).contains(&k));
+            counts[k as usize] += 1;
+        }
+
+        // The harmonic number H(10) is about 2.929, so k is drawn with probability 1 / 2.929k
+        for (k, &count) in counts.iter().enumerate().skip(1) {
+            let expected = 100_000.0 / (2.928_968 * k as f64);
+            assert!((count as f64 - expected).abs() < expected * 0.05);
+        }
+
+        assert!(counts[1] > counts[10] * 8);
+    }
+
+    #[test]
+    fn it_should_generate_zipf_integers_in_huge_ranges() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for _ in 0..1000 {
+            let k = rng.next_zipf(u64::MAX, 1.5);
+            assert!(k >= 1);
+        }
+
+        for _ in 0..1000 {
+            assert_eq!(rng.next_zipf(1, 2.0), 1);
+        }
+    }
+
+    #[test]
+    fn it_should_rejection_sample() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut sum = 0.0;
+
+        for _ in 0..10_000 {
+            let value = rng.rejection_sample(|rng| rng.next(), |x| x > 0.5);
+
+            assert!(value > 0.5 && value < 1.0);
+            sum += value;
+        }
+
+        assert!((sum / 10_000.0 - 0.75).abs() < 0.01);
+    }
+
+    #[test]
+    fn it_should_sample_from_inverse_cdfs() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+        let mut sum = 0.0;
+
+        for _ in 0..10_000 {
+            let value = a.sample_inverse_cdf(|u| u);
+
+            assert!(value > 0.0 && value < 1.0);
+            assert_eq!(value, b.next_open());
+
+            sum += value;
+        }
+
+        assert!((sum / 10_000.0 - 0.5).abs() < 0.01);
+
+        for _ in 0..10_000 {
+            let value = a.sample_inverse_cdf(|u| 3.0 + 2.0 * u);
+
+            assert!(value > 3.0 && value < 5.0);
+            assert_eq!(value, 3.0 + 2.0 * b.next_open());
+        }
+    }
+
+    #[test]
+    fn it_should_generate_booleans_from_odds() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let wins = (0..10_000).filter(|_| rng.gen_odds(3, 1)).count();
+
+        assert!(wins > 7_300 && wins < 7_700);
+
+        assert!(rng.gen_odds(1, 0));
+        assert!(!rng.gen_odds(0, 1));
+    }
+
+    #[test]
+    fn it_should_generate_arcsine_numbers() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut buckets = [0u32; 10];
+        let mut sum = 0.0;
+
+        for _ in 0..10_000 {
+            let value = rng.next_arcsine();
+
+            assert!((0.0..1.0).contains(&value));
+
+            buckets[(value * 10.0) as usize] += 1;
+            sum += value;
+        }
+
+        assert!((sum / 10_000.0 - 0.5).abs() < 0.01);
+
+        // The outer buckets each hold about 20% of the values, the middle ones about 6.4%
+        assert!(buckets[0] > 3 * buckets[4] && buckets[9] > 3 * buckets[5]);
+    }
+
+    #[test]
+    fn it_should_sample_piecewise_linear_densities() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let x = [1.0, 2.0, 4.0];
+        let y = [0.0, 2.0, 0.0];
+        let mut near_peak = 0;
+        let mut sum = 0.0;
+
+        for _ in 0..10_000 {
+            let value = rng.sample_piecewise_linear(&x, &y);
+
+            assert!((1.0..=4.0).contains(&value));
+
+            if (value - 2.0).abs() < 0.5 {
+                near_peak += 1;
+            }
+
+            sum += value;
+        }
+
+        // The triangle has mean (1 + 2 + 4) / 3, and 13 / 24 of its area lies within 0.5 of its peak
+        assert!((sum / 10_000.0 - 7.0 / 3.0).abs() < 0.02);
+        assert!(near_peak > 5_250 && near_peak < 5_600);
+    }
+
+    #[test]
+    fn it_should_generate_gumbel_numbers() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut sum = 0.0;
+
+        for _ in 0..100_000 {
+            let value = rng.next_gumbel(2.0, 3.0);
+
+            assert!(value.is_finite());
+            sum += value;
+        }
+
+        // The mean is the location plus the scale times the Euler-Mascheroni constant
+        assert!((sum / 100_000.0 - (2.0 + 3.0 * 0.5772156649015329)).abs() < 0.03);
+    }
+
+    #[test]
+    fn it_should_generate_hypergeometric_numbers() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for &(population, successes, draws) in [(50, 10, 20), (50, 10, 40), (1000, 300, 999)].iter() {
+            let mut sum = 0;
+
+            for _ in 0..10_000 {
+                let value = rng.next_hypergeometric(population, successes, draws);
+
+                assert!(value <= successes && value <= draws);
+                assert!(draws - value <= population - successes);
+
+                sum += value;
+            }
+
+            let expected = (draws * successes) as f64 / population as f64;
+
+            assert!((sum as f64 / 10_000.0 - expected).abs() < 0.05);
+        }
+
+        assert_eq!(rng.next_hypergeometric(10, 4, 10), 4);
+        assert_eq!(rng.next_hypergeometric(10, 4, 0), 0);
+    }
+
+    #[test]
+    fn it_should_generate_von_mises_angles() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut concentration = 0.0;
+
+        for _ in 0..10_000 {
+            let angle = rng.next_von_mises(3.0, 100.0);
+
+            assert!(angle > -PI && angle <= PI);
+            concentration += (angle - 3.0).cos();
+        }
+
+        // The mean resultant length is about 1 - 1 / (2 * kappa) for large kappa
+        assert!((concentration / 10_000.0 - 0.995).abs() < 0.001);
+
+        let mut buckets = [0u32; 8];
+
+        for _ in 0..16_000 {
+            let angle = rng.next_von_mises(1.0, 0.0);
+
+            assert!(angle > -PI && angle <= PI);
+            buckets[(((angle + PI) / (2.0 * PI) * 8.0) as usize).min(7)] += 1;
+        }
+
+        assert!(buckets.iter().all(|&count| count > 1_850 && count < 2_150));
+    }
+
+    #[test]
+    fn it_should_roll_exploding_dice() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let rolls = (0..10_000).map(|_| rng.roll_exploding(6)).collect::<Vec<_>>();
+
+        assert_eq!(rolls.iter().min(), Some(&1));
+        assert!(rolls.iter().any(|&roll| roll > 6));
+        assert!(rolls.iter().all(|&roll| roll % 6 != 0));
+
+        let mut again = XorShift128Plus::from_u32(4293262078);
+
+        assert!(rolls.iter().all(|&roll| roll == again.roll_exploding(6)));
+        assert_eq!(rng.roll_exploding(1), 101);
+    }
+
+    #[test]
+    fn it_should_generate_truncated_normals() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut sum = 0.0;
+
+        for _ in 0..10_000 {
+            let value = rng.next_truncated_normal(0.0, 1.0, 0.5, 3.0);
+
+            assert!((0.5..=3.0).contains(&value));
+            sum += value;
+        }
+
+        // The mean of a standard normal truncated to [0.5, 3] is about 1.13
+        assert!((sum / 10_000.0 - 1.13).abs() < 0.02);
+    }
+
+    #[test]
+    fn it_should_generate_stepped_values() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut seen = [false; 4];
+
+        for _ in 0..1000 {
+            let value = rng.next_stepped(1.0, 2.0, 0.25);
+            let k = (value - 1.0) / 0.25;
+
+            assert!((1.0..2.0).contains(&value));
+            assert_eq!(k, k.round());
+
+            seen[k as usize] = true;
+        }
+
+        assert!(seen.iter().all(|&s| s));
+
+        for _ in 0..1000 {
+            assert!(rng.next_stepped(0.0, 1.1, 0.5) <= 1.0);
+        }
+
+        for _ in 0..1000 {
+            assert!(rng.next_stepped(0.0, 2.1, 0.3) < 2.1);
+            assert!(rng.next_stepped(0.0, 0.07, 0.01) < 0.07);
+            assert!(rng.next_stepped(0.0, 4.2, 0.3) < 4.2);
+        }
+    }
+
+    #[test]
+    fn it_should_sample_from_histograms() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let edges = [0.0, 1.0, 3.0, 4.0];
+        let counts = [1, 8, 0];
+        let mut heavy = 0;
+
+        for _ in 0..10_000 {
+            let value = rng.sample_histogram(&edges, &counts);
+
+            assert!((0.0..3.0).contains(&value));
+
+            if value >= 1.0 {
+                heavy += 1;
+            }
+        }
+
+        assert!(heavy > 8_700 && heavy < 9_100);
+    }
+
+    #[test]
+    fn it_should_generate_normals_with_the_ziggurat_method() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let samples: Vec<f64> = (0..100_000).map(|_| rng.next_normal_ziggurat()).collect();
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+
+        assert!(mean.abs() < 0.01);
+        assert!((variance - 1.0).abs() < 0.02);
+
+        // About 0.026% of draws should land in the tail beyond `ZIG_NORM_R`
+        let tail = samples.iter().filter(|x| x.abs() > 3.654).count();
+
+        assert!(tail > 5 && tail < 60);
+    }
+
+    #[test]
+    fn it_should_generate_normals_with_box_muller() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let samples: Vec<f64> = (0..100_000).map(|_| rng.next_gaussian()).collect();
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+
+        assert!(mean.abs() < 0.01);
+        assert!((variance - 1.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn it_should_generate_negative_binomials() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for &(r, p) in [(3.0, 0.4), (0.5, 0.5), (20.0, 0.1)].iter() {
+            let samples: Vec<f64> = (0..50_000)
+                .map(|_| rng.next_negative_binomial(r, p) as f64)
+                .collect();
+
+            let n = samples.len() as f64;
+            let mean = samples.iter().sum::<f64>() / n;
+            let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+
+            let expected_mean = r * (1.0 - p) / p;
+            let expected_variance = expected_mean / p;
+
+            assert!((mean - expected_mean).abs() < expected_mean * 0.02);
+            assert!((variance - expected_variance).abs() < expected_variance * 0.05);
+        }
+
+        assert_eq!(rng.next_negative_binomial(3.0, 1.0), 0);
+    }
+
+    #[test]
+    fn it_should_compute_ln_gamma() {
+        assert!((super::ln_gamma(0.5) - 0.5723649429247001).abs() < 1e-12);
+        assert!((super::ln_gamma(5.0) - 24f64.ln()).abs() < 1e-12);
+        assert!((super::ln_gamma(100.0) - 359.1342053695754).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_should_generate_levels() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut counts = [0u32; 4];
+
+        for _ in 0..10_000 {
+            counts[rng.next_level(0.25, 3) as usize] += 1;
+        }
+
+        assert!(counts[0] > counts[1] && counts[1] > counts[2] && counts[2] > counts[3]);
+        assert!(counts[0] > 7_300 && counts[0] < 7_700);
+        assert!(counts[1] > 1_700 && counts[1] < 2_050);
+
+        assert_eq!(rng.next_level(1.0, 5), 5);
+        assert_eq!(rng.next_level(0.0, 5), 0);
+    }
+
+    #[test]
+    fn it_should_generate_laplace_distributed_numbers() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut samples: Vec<f64> = (0..10_001).map(|_| rng.next_laplace(3.0, 2.0)).collect();
+
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert!(samples.iter().all(|x| x.is_finite()));
+        assert!((samples[5_000] - 3.0).abs() < 0.1);
+
+        // The quartiles of a Laplace distribution lie `scale * ln(2)` from the location
+        assert!((samples[2_500] - (3.0 - 2.0 * 2f64.ln())).abs() < 0.1);
+        assert!((samples[7_500] - (3.0 + 2.0 * 2f64.ln())).abs() < 0.1);
+    }
+
+    #[test]
+    fn it_should_generate_exponential_numbers() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut samples: Vec<f64> = (0..10_001).map(|_| rng.next_exp(2.0)).collect();
+
+        assert!(samples.iter().all(|&x| x > 0.0 && x.is_finite()));
+        assert!((samples.iter().sum::<f64>() / 10_001.0 - 0.5).abs() < 0.01);
+
+        // The median of an exponential distribution is `ln(2) / lambda`
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert!((samples[5_000] - 2f64.ln() / 2.0).abs() < 0.02);
+    }
+}
@@ -0,0 +1,136 @@
+use super::XorShift128Plus;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+    impl Sealed for f64 {}
+}
+
+/// Types that [`XorShift128Plus::fill`](struct.XorShift128Plus.html#method.fill) can fill a
+/// slice of. This trait is sealed and implemented for `u8`, `u32`, `u64` and `f64`.
+pub trait Fillable: sealed::Sealed + Sized {
+    #[doc(hidden)]
+    fn fill_slice (rng: &mut XorShift128Plus, dest: &mut [Self]);
+}
+
+impl Fillable for u8 {
+    fn fill_slice (rng: &mut XorShift128Plus, dest: &mut [u8]) {
+        rng.fill_bytes(dest);
+    }
+}
+
+impl Fillable for u32 {
+    fn fill_slice (rng: &mut XorShift128Plus, dest: &mut [u32]) {
+        for value in dest {
+            *value = rng.next_u32();
+        }
+    }
+}
+
+impl Fillable for u64 {
+    fn fill_slice (rng: &mut XorShift128Plus, dest: &mut [u64]) {
+        for value in dest {
+            *value = rng.next_u64();
+        }
+    }
+}
+
+impl Fillable for f64 {
+    fn fill_slice (rng: &mut XorShift128Plus, dest: &mut [f64]) {
+        for value in dest {
+            *value = rng.next();
+        }
+    }
+}
+
+impl XorShift128Plus {
+    /// Fills `dest` with psuedo-random values. Integers are filled with raw output, using one
+    /// step per eight bytes for `u8` and the upper 32 bits of a step for `u32`, while floats are
+    /// filled with the same values as repeated calls to [`next`](#method.next) would return.
+    pub fn fill<T: Fillable> (&mut self, dest: &mut [T]) {
+        T::fill_slice(self, dest);
+    }
+
+    /// Replaces the contents of `buf` with `count` psuedo-random numbers between 0 (inclusivly)
+    /// and 1 (exclusivly), the same as `count` sequential calls to [`next`](#method.next). The
+    /// existing capacity is reused, so refilling a buffer every frame doesn't allocate once it's
+    /// large enough.
+    #[cfg(feature = "std")]
+    pub fn refill (&mut self, buf: &mut Vec<f64>, count: usize) {
+        buf.clear();
+        buf.reserve(count);
+        buf.extend((0..count).map(|_| self.next()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XorShift128Plus;
+
+    #[test]
+    fn it_should_fill_u64_slices() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+        let mut values = [0u64; 10];
+
+        a.fill(&mut values);
+
+        for &value in values.iter() {
+            assert_eq!(value, b.next_u64());
+        }
+    }
+
+    #[test]
+    fn it_should_fill_f64_slices() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+        let mut values = [0.0; 10];
+
+        a.fill(&mut values);
+
+        for &value in values.iter() {
+            assert_eq!(value, b.next());
+        }
+    }
+
+    #[test]
+    fn it_should_fill_byte_slices() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+        let mut bytes = [0u8; 12];
+
+        a.fill(&mut bytes);
+
+        assert_eq!(bytes[0..8], b.next_u64().to_le_bytes());
+        assert_eq!(bytes[8..12], b.next_u64().to_le_bytes()[0..4]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_refill_buffers_in_place() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+        let mut buf = Vec::new();
+
+        a.refill(&mut buf, 100);
+
+        assert_eq!(buf.len(), 100);
+
+        for &value in buf.iter() {
+            assert_eq!(value, b.next());
+        }
+
+        let capacity = buf.capacity();
+        let pointer = buf.as_ptr();
+
+        a.refill(&mut buf, 50);
+
+        assert_eq!(buf.len(), 50);
+        assert_eq!(buf.capacity(), capacity);
+        assert_eq!(buf.as_ptr(), pointer);
+        assert_eq!(buf[0], b.next());
+    }
+}
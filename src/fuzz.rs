@@ -0,0 +1,105 @@
+use super::{XorShift128Plus, MANTISSA_MASK};
+
+const EXPONENT_MASK: u64 = 0x7ff;
+const EXPONENT_BIAS: i16 = 1023;
+
+impl XorShift128Plus {
+    /// Returns a psuedo-random finite float, which may be negative, zero, subnormal or
+    /// arbitrarily large. The sign, exponent and mantissa are taken from the raw bits of a step,
+    /// redrawing whenever they would form a NaN or an infinity. Note that this is uniform over
+    /// the bit patterns of finite floats, not over the real line, so about half of the values
+    /// have a magnitude below 1.
+    pub fn next_finite_f64 (&mut self) -> f64 {
+        loop {
+            let bits = self.next_u64();
+
+            if (bits >> 52) & EXPONENT_MASK != EXPONENT_MASK {
+                return f64::from_bits(bits);
+            }
+        }
+    }
+
+    /// Returns the sign, unbiased exponent and 52-bit mantissa of a float drawn like
+    /// `next_finite_f64`, for asserting on specific bit fields. The exponent is the raw field
+    /// minus 1023, so zero and subnormals report -1023, and the mantissa excludes the implicit
+    /// leading bit.
+    pub fn next_parts (&mut self) -> (bool, i16, u64) {
+        let bits = self.next_finite_f64().to_bits();
+        let exponent = ((bits >> 52) & EXPONENT_MASK) as i16 - EXPONENT_BIAS;
+
+        (bits >> 63 == 1, exponent, bits & MANTISSA_MASK)
+    }
+
+    /// Returns the octets of a psuedo-random IPv4 address, which can be turned into a
+    /// `std::net::Ipv4Addr` with `Ipv4Addr::from`. Every address is possible, including
+    /// reserved ones such as loopback and broadcast addresses.
+    pub fn random_ipv4 (&mut self) -> [u8; 4] {
+        let mut octets = [0u8; 4];
+        self.fill_bytes(&mut octets);
+        octets
+    }
+
+    /// Returns the octets of a psuedo-random IPv6 address, which can be turned into a
+    /// `std::net::Ipv6Addr` with `Ipv6Addr::from`. Every address is possible, including
+    /// reserved ones.
+    pub fn random_ipv6 (&mut self) -> [u8; 16] {
+        let mut octets = [0u8; 16];
+        self.fill_bytes(&mut octets);
+        octets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XorShift128Plus;
+
+    #[test]
+    fn it_should_generate_finite_floats() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut negative = 0;
+
+        for _ in 0..100_000 {
+            let value = rng.next_finite_f64();
+
+            assert!(value.is_finite());
+
+            if value.is_sign_negative() {
+                negative += 1;
+            }
+        }
+
+        assert!(negative > 49_000 && negative < 51_000);
+    }
+
+    #[test]
+    fn it_should_decompose_finite_floats() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut expected = XorShift128Plus::from_u32(4293262078);
+
+        for _ in 0..10_000 {
+            let (sign, exponent, mantissa) = rng.next_parts();
+
+            assert!(mantissa < 1 << 52);
+            assert!((-1023..1024).contains(&exponent));
+
+            let bits = (sign as u64) << 63 | ((exponent + 1023) as u64) << 52 | mantissa;
+
+            assert_eq!(f64::from_bits(bits), expected.next_finite_f64());
+        }
+    }
+
+    #[test]
+    fn it_should_generate_ip_addresses() {
+        let mut rng = XorShift128Plus::from_bytes([
+            0x5d, 0x28, 0x94, 0x50, 0xc8, 0x88, 0xf9, 0x9b,
+            0x5e, 0x5c, 0x1f, 0xd1, 0x35, 0x09, 0xe3, 0x9e,
+        ]);
+
+        assert_eq!(rng.random_ipv4(), [219, 98, 198, 199]);
+        assert_eq!(rng.random_ipv4(), [14, 179, 255, 125]);
+        assert_eq!(rng.random_ipv6(), [
+            111, 19, 58, 122, 217, 30, 170, 124,
+            125, 138, 90, 162, 175, 86, 123, 57,
+        ]);
+    }
+}
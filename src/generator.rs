@@ -0,0 +1,97 @@
+use super::{ldexp, ParkMiller, SplitMix64, XorShift128Plus, MANTISSA_BITS, MANTISSA_MASK};
+
+/// The algorithms a [`Generator`](struct.Generator.html) can be backed by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// [`XorShift128Plus`](struct.XorShift128Plus.html), seeded with `from_u64`.
+    XorShift128Plus,
+    /// [`SplitMix64`](struct.SplitMix64.html).
+    SplitMix64,
+    /// [`ParkMiller`](struct.ParkMiller.html), seeded with the lower 32 bits of the seed.
+    ParkMiller,
+}
+
+/// A generator whose algorithm is chosen at runtime, e.g. for comparing the quality of
+/// different algorithms without changing any call sites.
+///
+/// # Examples
+///
+/// ```
+/// extern crate xorshift128plus;
+///
+/// use xorshift128plus::{Algorithm, Generator};
+///
+/// # fn main() {
+/// let mut rng = Generator::new(Algorithm::SplitMix64, 42);
+///
+/// println!("First random float: {}", rng.next_f64());
+/// # }
+/// ```
+pub struct Generator {
+    algo: Algorithm,
+    backend: Backend,
+}
+
+enum Backend {
+    XorShift128Plus(XorShift128Plus),
+    SplitMix64(SplitMix64),
+    ParkMiller(ParkMiller),
+}
+
+impl Generator {
+    /// Constructs a new generator running `algo`, seeded with `seed`.
+    pub fn new (algo: Algorithm, seed: u64) -> Generator {
+        let backend = match algo {
+            Algorithm::XorShift128Plus => Backend::XorShift128Plus(XorShift128Plus::from_u64(seed)),
+            Algorithm::SplitMix64 => Backend::SplitMix64(SplitMix64::new(seed)),
+            Algorithm::ParkMiller => Backend::ParkMiller(ParkMiller::new(seed as u32)),
+        };
+
+        Generator { algo, backend }
+    }
+
+    /// Returns the algorithm backing this generator.
+    pub fn algorithm (&self) -> Algorithm {
+        self.algo
+    }
+
+    /// Returns the next psuedo-random number between 0 (inclusivly) and 1 (exclusivly). For
+    /// `XorShift128Plus` this is [`next`](struct.XorShift128Plus.html#method.next), for
+    /// `SplitMix64` the lower 52 bits of the output are used the same way, and for `ParkMiller`
+    /// it's [`next_f64`](struct.ParkMiller.html#method.next_f64), which is never 0.
+    pub fn next_f64 (&mut self) -> f64 {
+        match self.backend {
+            Backend::XorShift128Plus(ref mut rng) => rng.next(),
+            Backend::SplitMix64(ref mut rng) => ldexp(rng.next_u64() & MANTISSA_MASK, -MANTISSA_BITS),
+            Backend::ParkMiller(ref mut rng) => rng.next_f64(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ldexp, Algorithm, Generator, ParkMiller, SplitMix64, XorShift128Plus, MANTISSA_BITS, MANTISSA_MASK};
+
+    #[test]
+    fn it_should_run_each_backend() {
+        let mut xorshift = Generator::new(Algorithm::XorShift128Plus, 42);
+        let mut splitmix = Generator::new(Algorithm::SplitMix64, 42);
+        let mut park_miller = Generator::new(Algorithm::ParkMiller, 1);
+
+        assert_eq!(xorshift.algorithm(), Algorithm::XorShift128Plus);
+        assert_eq!(splitmix.algorithm(), Algorithm::SplitMix64);
+        assert_eq!(park_miller.algorithm(), Algorithm::ParkMiller);
+
+        let mut xorshift_reference = XorShift128Plus::from_u64(42);
+        let mut splitmix_reference = SplitMix64::new(42);
+        let mut park_miller_reference = ParkMiller::new(1);
+
+        for _ in 0..10 {
+            assert_eq!(xorshift.next_f64(), xorshift_reference.next());
+            assert_eq!(splitmix.next_f64(), ldexp(splitmix_reference.next_u64() & MANTISSA_MASK, -MANTISSA_BITS));
+            assert_eq!(park_miller.next_f64(), park_miller_reference.next_f64());
+        }
+
+        assert_eq!(Generator::new(Algorithm::ParkMiller, 1).next_f64(), 48271.0 / 2_147_483_647.0);
+    }
+}
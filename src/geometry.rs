@@ -0,0 +1,146 @@
+use std::f64::consts::PI;
+
+use super::XorShift128Plus;
+
+impl XorShift128Plus {
+    /// Returns a psuedo-random angle in radians, between 0 (inclusivly) and 2π (exclusivly).
+    pub fn random_angle (&mut self) -> f64 {
+        self.next() * 2.0 * PI
+    }
+
+    /// Returns a psuedo-random 2D unit vector as `(x, y)`, pointing in a uniformly chosen
+    /// direction, i.e. `(cos θ, sin θ)` for an angle `θ` from
+    /// [`random_angle`](#method.random_angle).
+    pub fn random_direction_2d (&mut self) -> (f64, f64) {
+        let angle = self.random_angle();
+        (angle.cos(), angle.sin())
+    }
+
+    /// Returns a psuedo-random unit quaternion as `[x, y, z, w]`, representing a rotation chosen
+    /// uniformly over all 3D rotations. It's made from three uniform draws using Ken Shoemake's
+    /// method, described in "Uniform random rotations" (Graphics Gems III).
+    pub fn random_quaternion (&mut self) -> [f64; 4] {
+        let u1 = self.next();
+        let u2 = self.next() * 2.0 * PI;
+        let u3 = self.next() * 2.0 * PI;
+
+        let a = (1.0 - u1).sqrt();
+        let b = u1.sqrt();
+
+        [a * u2.sin(), a * u2.cos(), b * u3.sin(), b * u3.cos()]
+    }
+
+    /// Returns a psuedo-random point, chosen uniformly inside the polygon with the given
+    /// vertices. The polygon is split into a fan of triangles around the first vertex, so it
+    /// must be convex, or at least star-shaped around the first vertex. A triangle is chosen
+    /// proportionally to its area, and the point is then chosen uniformly inside of it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than 3 vertices, or if the polygon has no area.
+    pub fn in_polygon (&mut self, vertices: &[(f64, f64)]) -> (f64, f64) {
+        assert!(vertices.len() >= 3, "a polygon needs at least 3 vertices");
+
+        let (ax, ay) = vertices[0];
+        let area = |i: usize| {
+            let (bx, by) = vertices[i + 1];
+            let (cx, cy) = vertices[i + 2];
+
+            ((bx - ax) * (cy - ay) - (cx - ax) * (by - ay)).abs()
+        };
+
+        let i = self.weighted_index_by(vertices.len() - 2, area);
+        let (bx, by) = vertices[i + 1];
+        let (cx, cy) = vertices[i + 2];
+
+        let mut r1 = self.next();
+        let mut r2 = self.next();
+
+        // Fold points from the far half of the parallelogram back into the triangle
+        if r1 + r2 > 1.0 {
+            r1 = 1.0 - r1;
+            r2 = 1.0 - r2;
+        }
+
+        (ax + r1 * (bx - ax) + r2 * (cx - ax), ay + r1 * (by - ay) + r2 * (cy - ay))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::XorShift128Plus;
+
+    #[test]
+    fn it_should_generate_uniform_rotations() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut buckets = [0u32; 4];
+        let mut sum = [0.0; 3];
+
+        for _ in 0..10_000 {
+            let [x, y, z, w] = rng.random_quaternion();
+
+            assert!((x * x + y * y + z * z + w * w - 1.0).abs() < 1e-12);
+
+            // Rotate the north pole (0, 0, 1), which should land uniformly on the sphere
+            let pole = [2.0 * (x * z + w * y), 2.0 * (y * z - w * x), 1.0 - 2.0 * (x * x + y * y)];
+
+            for i in 0..3 {
+                sum[i] += pole[i];
+            }
+
+            // On a uniform sphere, the height is uniform between -1 and 1
+            buckets[(((pole[2] + 1.0) * 2.0) as usize).min(3)] += 1;
+        }
+
+        for &s in sum.iter() {
+            assert!((s / 10_000.0).abs() < 0.02);
+        }
+
+        for &count in buckets.iter() {
+            assert!(count > 2_300 && count < 2_700);
+        }
+    }
+
+    #[test]
+    fn it_should_generate_points_in_polygons() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let square = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        let mut sum = (0.0, 0.0);
+
+        for _ in 0..10_000 {
+            let (x, y) = rng.in_polygon(&square);
+
+            assert!((0.0..=1.0).contains(&x));
+            assert!((0.0..=1.0).contains(&y));
+
+            sum.0 += x;
+            sum.1 += y;
+        }
+
+        assert!((sum.0 / 10_000.0 - 0.5).abs() < 0.01);
+        assert!((sum.1 / 10_000.0 - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn it_should_generate_uniform_directions() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut quadrants = [0u32; 4];
+
+        for _ in 0..10_000 {
+            let (x, y) = rng.random_direction_2d();
+
+            assert!((x * x + y * y - 1.0).abs() < 1e-12);
+
+            let angle = rng.random_angle();
+
+            assert!((0.0..2.0 * PI).contains(&angle));
+            quadrants[(angle / (0.5 * PI)) as usize] += 1;
+        }
+
+        for &count in quadrants.iter() {
+            assert!(count > 2_300 && count < 2_700);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use super::XorShift128Plus;
+
+impl XorShift128Plus {
+    /// Returns the edges of a psuedo-random Erdős–Rényi graph on `nodes` nodes, as
+    /// `(from, to, weight)` with `from < to`. Each of the `nodes * (nodes - 1) / 2` possible
+    /// edges exists independently with probability `edge_prob`, and gets a weight between
+    /// `weight_range.0` (inclusivly) and `weight_range.1` (exclusivly).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `edge_prob` is not between 0 and 1 (inclusivly), or if `weight_range.0` is
+    /// greater than `weight_range.1`.
+    pub fn random_weighted_graph (
+        &mut self,
+        nodes: usize,
+        edge_prob: f64,
+        weight_range: (f64, f64),
+    ) -> Vec<(usize, usize, f64)> {
+        assert!((0.0..=1.0).contains(&edge_prob), "edge_prob must be between 0 and 1");
+        assert!(weight_range.0 <= weight_range.1, "the weight range must not be reversed");
+
+        let (low, high) = weight_range;
+        let mut edges = Vec::new();
+
+        for from in 0..nodes {
+            for to in from + 1..nodes {
+                if self.next() < edge_prob {
+                    edges.push((from, to, low + self.next() * (high - low)));
+                }
+            }
+        }
+
+        edges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XorShift128Plus;
+
+    #[test]
+    fn it_should_generate_weighted_graphs() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let edges = rng.random_weighted_graph(200, 0.1, (1.0, 5.0));
+
+        // There are 19 900 possible edges, so about 1990 should exist
+        assert!(edges.len() > 1_860 && edges.len() < 2_120);
+
+        for &(from, to, weight) in edges.iter() {
+            assert!(from < to && to < 200);
+            assert!((1.0..5.0).contains(&weight));
+        }
+
+        assert!(rng.random_weighted_graph(10, 0.0, (1.0, 5.0)).is_empty());
+        assert_eq!(rng.random_weighted_graph(10, 1.0, (1.0, 5.0)).len(), 45);
+    }
+}
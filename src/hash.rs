@@ -0,0 +1,192 @@
+use core::hash::Hasher;
+
+use super::{ldexp, splitmix64, u64_from_bytes, XorShift128Plus, MANTISSA_BITS, MANTISSA_MASK};
+
+/// Returns a psuedo-random number between 0 (inclusivly) and 1 (exclusivly) determined purely
+/// by the coordinates `x`, `y` and `seed`, by scrambling them with splitmix64. Since there is no
+/// state, it's well suited for noise functions indexed by grid position.
+///
+/// # Examples
+///
+/// ```
+/// extern crate xorshift128plus;
+///
+/// use xorshift128plus::hash_to_f64;
+///
+/// # fn main() {
+/// let height = hash_to_f64(12, 34, 42);
+///
+/// assert_eq!(height, hash_to_f64(12, 34, 42));
+/// # }
+/// ```
+pub fn hash_to_f64 (x: u64, y: u64, seed: u64) -> f64 {
+    let hash = splitmix64(splitmix64(splitmix64(seed) ^ x) ^ y);
+
+    ldexp(hash & MANTISSA_MASK, -MANTISSA_BITS)
+}
+
+/// Lets the generator be used as a deterministic, seeded `Hasher`. Bytes are folded into the
+/// state eight at a time, each word followed by one xorshift step, and `finish` returns the raw
+/// 64-bit output the next step would produce. Note that this is not a cryptographic hash.
+impl Hasher for XorShift128Plus {
+    fn write (&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+
+            self.s1 ^= u64_from_bytes(&word);
+            self.next_u64();
+        }
+    }
+
+    fn finish (&self) -> u64 {
+        XorShift128Plus::from_raw(self.s0, self.s1).next_u64()
+    }
+}
+
+impl XorShift128Plus {
+    /// Returns a psuedo-random number between 0 (inclusivly) and 1 (exclusivly) determined purely
+    /// by `base_seed` and `index`, without any mutable generator. The index is scrambled into a
+    /// fresh state with splitmix64 and a single value is drawn from it, so work can be split
+    /// across threads by index while staying reproducible.
+    pub fn at (base_seed: [u8; 16], index: u64) -> f64 {
+        let scrambled = splitmix64(index);
+
+        XorShift128Plus::from_raw(
+            splitmix64(u64_from_bytes(&base_seed[0..8]) ^ scrambled),
+            splitmix64(u64_from_bytes(&base_seed[8..16]) ^ splitmix64(scrambled)),
+        ).next()
+    }
+
+    /// Returns a 64-bit hash of `data`, seeded by the current state, without advancing this
+    /// generator. The same state and data always give the same hash, which makes it useful for
+    /// deterministically bucketing keys. Note that this is not a cryptographic hash.
+    pub fn hash_bytes (&self, data: &[u8]) -> u64 {
+        let mut hasher = XorShift128Plus::from_raw(self.s0, self.s1);
+
+        hasher.write(data);
+        hasher.write(&(data.len() as u64).to_le_bytes());
+
+        splitmix64(hasher.finish())
+    }
+
+    /// Returns a new generator derived from the current state and `label`, without advancing
+    /// this generator. The same state and label always give the same generator, while different
+    /// labels give independent looking streams, e.g. one per subsystem of a game.
+    pub fn derive (&self, label: &str) -> XorShift128Plus {
+        let mut hasher = XorShift128Plus::from_raw(self.s0, self.s1);
+
+        hasher.write(label.as_bytes());
+        hasher.write(&(label.len() as u64).to_le_bytes());
+
+        let s0 = splitmix64(hasher.next_u64());
+        let s1 = splitmix64(hasher.next_u64());
+
+        XorShift128Plus::from_raw(s0, s1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::Hasher;
+
+    use super::{hash_to_f64, XorShift128Plus};
+
+    #[test]
+    fn it_should_hash_deterministically() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        a.write(b"the quick brown fox");
+        b.write(b"the quick brown fox");
+
+        assert_eq!(a.finish(), b.finish());
+        assert_eq!(a.finish(), a.finish());
+
+        let mut c = XorShift128Plus::from_u32(4293262078);
+        c.write(b"the quick brown cat");
+
+        assert_ne!(a.finish(), c.finish());
+    }
+
+    #[test]
+    fn it_should_derive_labeled_generators() {
+        let rng = XorShift128Plus::from_u32(4293262078);
+
+        let mut ai = rng.derive("ai");
+        let mut physics = rng.derive("physics");
+        let mut again = rng.derive("ai");
+
+        for _ in 0..10 {
+            let value = ai.next();
+
+            assert_ne!(value, physics.next());
+            assert_eq!(value, again.next());
+        }
+    }
+
+    #[test]
+    fn it_should_hash_coordinates_to_floats() {
+        assert_eq!(hash_to_f64(12, 34, 42), hash_to_f64(12, 34, 42));
+        assert_ne!(hash_to_f64(12, 34, 42), hash_to_f64(34, 12, 42));
+        assert_ne!(hash_to_f64(12, 34, 42), hash_to_f64(12, 34, 43));
+
+        // Neighbouring cells should look uncorrelated
+        let mut sum = 0.0;
+        let mut product = 0.0;
+
+        for x in 0..100 {
+            for y in 0..100 {
+                let value = hash_to_f64(x, y, 42);
+
+                assert!((0.0..1.0).contains(&value));
+
+                sum += value;
+                product += (value - 0.5) * (hash_to_f64(x + 1, y, 42) - 0.5);
+            }
+        }
+
+        assert!((sum / 10_000.0 - 0.5).abs() < 0.01);
+        assert!((product / 10_000.0 * 12.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn it_should_draw_by_index() {
+        let seed = [
+            0x5d, 0x28, 0x94, 0x50, 0xc8, 0x88, 0xf9, 0x9b,
+            0x5e, 0x5c, 0x1f, 0xd1, 0x35, 0x09, 0xe3, 0x9e,
+        ];
+
+        assert_eq!(XorShift128Plus::at(seed, 5), XorShift128Plus::at(seed, 5));
+        assert_ne!(XorShift128Plus::at(seed, 5), XorShift128Plus::at([0; 16], 5));
+
+        // Neighbouring indices should look uncorrelated
+        let mut sum = 0.0;
+        let mut product = 0.0;
+
+        for index in 0..10_000 {
+            let value = XorShift128Plus::at(seed, index);
+
+            assert!((0.0..1.0).contains(&value));
+
+            sum += value;
+            product += (value - 0.5) * (XorShift128Plus::at(seed, index + 1) - 0.5);
+        }
+
+        assert!((sum / 10_000.0 - 0.5).abs() < 0.01);
+        assert!((product / 10_000.0 * 12.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn it_should_hash_bytes_without_advancing() {
+        let a = XorShift128Plus::from_u32(4293262078);
+        let b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.hash_bytes(b"user:1234"), b.hash_bytes(b"user:1234"));
+        assert_ne!(a.hash_bytes(b"user:1234"), a.hash_bytes(b"user:1235"));
+        assert_ne!(a.hash_bytes(b""), a.hash_bytes(b"\0"));
+        assert_ne!(a.hash_bytes(b"user:1234"), XorShift128Plus::from_u32(1).hash_bytes(b"user:1234"));
+
+        assert_eq!(a.draws(), 0);
+    }
+}
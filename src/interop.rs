@@ -0,0 +1,85 @@
+use rand_core::{Error, RngCore, SeedableRng};
+
+use super::XorShift128Plus;
+
+/// Lets the generator be used with the `rand` ecosystem, e.g. with its distributions and slice
+/// helpers. Requires the `rand_core` feature. `next_u64` returns the raw output of each step,
+/// the same one [`next`](struct.XorShift128Plus.html#method.next) takes its lower 52 bits from,
+/// `next_u32` returns the upper 32 bits of a step, and `fill_bytes` fills the buffer in
+/// little-endian order, eight bytes per step.
+impl RngCore for XorShift128Plus {
+    fn next_u32 (&mut self) -> u32 {
+        XorShift128Plus::next_u32(self)
+    }
+
+    fn next_u64 (&mut self) -> u64 {
+        XorShift128Plus::next_u64(self)
+    }
+
+    fn fill_bytes (&mut self, dest: &mut [u8]) {
+        XorShift128Plus::fill_bytes(self, dest)
+    }
+
+    fn try_fill_bytes (&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        XorShift128Plus::fill_bytes(self, dest);
+        Ok(())
+    }
+}
+
+/// Seeds the generator the same way as the inherent constructors, i.e. `from_seed` is
+/// [`from_bytes`](struct.XorShift128Plus.html#method.from_bytes) and `seed_from_u64` is
+/// [`from_u64`](struct.XorShift128Plus.html#method.from_u64). Requires the `rand_core` feature.
+impl SeedableRng for XorShift128Plus {
+    type Seed = [u8; 16];
+
+    fn from_seed (seed: [u8; 16]) -> XorShift128Plus {
+        XorShift128Plus::from_bytes(seed)
+    }
+
+    fn seed_from_u64 (seed: u64) -> XorShift128Plus {
+        XorShift128Plus::from_u64(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::XorShift128Plus;
+
+    #[test]
+    fn it_should_match_the_float_sequence() {
+        let seed = [
+            0x5d, 0x28, 0x94, 0x50, 0xc8, 0x88, 0xf9, 0x9b,
+            0x5e, 0x5c, 0x1f, 0xd1, 0x35, 0x09, 0xe3, 0x9e,
+        ];
+
+        let mut rng = XorShift128Plus::from_seed(seed);
+        let mut reference = XorShift128Plus::from_bytes(seed);
+
+        for _ in 0..100 {
+            let bits = RngCore::next_u64(&mut rng);
+
+            assert_eq!((bits & ((1 << 52) - 1)) as f64 / (1u64 << 52) as f64, reference.next());
+        }
+    }
+
+    #[test]
+    fn it_should_implement_rng_core() {
+        let mut a = XorShift128Plus::seed_from_u64(42);
+        let mut b = XorShift128Plus::from_u64(42);
+
+        assert_eq!(RngCore::next_u32(&mut a), (XorShift128Plus::next_u64(&mut b) >> 32) as u32);
+
+        let mut bytes = [0u8; 12];
+        a.fill_bytes(&mut bytes);
+
+        assert_eq!(&bytes[..8], &XorShift128Plus::next_u64(&mut b).to_le_bytes());
+        assert_eq!(&bytes[8..], &XorShift128Plus::next_u64(&mut b).to_le_bytes()[..4]);
+
+        let mut more = [0u8; 5];
+
+        assert!(a.try_fill_bytes(&mut more).is_ok());
+        assert_eq!(a.draws(), 4);
+    }
+}
@@ -0,0 +1,92 @@
+use super::XorShift128Plus;
+
+const MAX_CONTAINER_LEN: u64 = 4;
+const MAX_STRING_BYTES: u64 = 16;
+
+/// A JSON value, as generated by
+/// [`XorShift128Plus::random_json`](struct.XorShift128Plus.html#method.random_json). Objects
+/// are kept as a list of key-value pairs, so that their order is reproducible. Note that
+/// random objects may contain duplicate keys.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl XorShift128Plus {
+    fn random_json_string (&mut self) -> String {
+        let max_bytes = self.next_below(MAX_STRING_BYTES + 1) as usize;
+        self.utf8_string(max_bytes)
+    }
+
+    /// Returns a psuedo-random JSON value, e.g. for property-based testing of serializers. Arrays
+    /// and objects hold at most four values each and are nested at most `max_depth` levels deep,
+    /// so a `max_depth` of 0 only gives scalars. Numbers are arbitrary finite floats from
+    /// [`next_finite_f64`](#method.next_finite_f64), and strings are made from
+    /// [`utf8_string`](#method.utf8_string).
+    pub fn random_json (&mut self, max_depth: u32) -> JsonValue {
+        let kinds = if max_depth == 0 { 4 } else { 6 };
+
+        match self.next_below(kinds) {
+            0 => JsonValue::Null,
+            1 => JsonValue::Bool(self.random_sign() > 0.0),
+            2 => JsonValue::Number(self.next_finite_f64()),
+            3 => JsonValue::String(self.random_json_string()),
+            4 => {
+                let len = self.next_below(MAX_CONTAINER_LEN + 1);
+                JsonValue::Array((0..len).map(|_| self.random_json(max_depth - 1)).collect())
+            },
+            _ => {
+                let len = self.next_below(MAX_CONTAINER_LEN + 1);
+                let pairs = (0..len).map(|_| {
+                    let key = self.random_json_string();
+                    (key, self.random_json(max_depth - 1))
+                });
+
+                JsonValue::Object(pairs.collect())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsonValue, XorShift128Plus};
+
+    fn depth (value: &JsonValue) -> u32 {
+        match *value {
+            JsonValue::Array(ref values) => 1 + values.iter().map(depth).max().unwrap_or(0),
+            JsonValue::Object(ref pairs) => {
+                1 + pairs.iter().map(|pair| depth(&pair.1)).max().unwrap_or(0)
+            },
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn it_should_generate_json_within_the_depth_bound() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut deepest = 0;
+
+        for max_depth in 0..5 {
+            for _ in 0..100 {
+                let value = rng.random_json(max_depth);
+                let value_depth = depth(&value);
+
+                assert!(value_depth <= max_depth);
+                deepest = deepest.max(value_depth);
+            }
+        }
+
+        assert_eq!(deepest, 4);
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.random_json(3), b.random_json(3));
+    }
+}
@@ -0,0 +1,110 @@
+use super::XorShift128Plus;
+
+// The polynomials x^(2^64) and x^(2^96) modulo the characteristic polynomial of the generator,
+// with the coefficient of x^i at bit i % 64 of word i / 64. Note that these differ from the
+// ones published for xorshift128+, since those are for the later shift constants 23, 18 and 5.
+const JUMP: [u64; 2] = [0x8c405782bca686ad, 0xc44f35946fef49c6];
+const LONG_JUMP: [u64; 2] = [0xeec5431970b882bc, 0x397adbe826b37b9e];
+
+impl XorShift128Plus {
+    /// Advances the generator 2^64 steps, in time equivalent to 128 steps. Generators jumped a
+    /// different number of times from the same seed produce non-overlapping subsequences of
+    /// 2^64 values each, e.g. one per worker thread. Jumping doesn't count towards
+    /// [`draws`](#method.draws).
+    pub fn jump (&mut self) {
+        self.jump_by(JUMP);
+    }
+
+    /// Advances the generator 2^96 steps, in time equivalent to 128 steps. This is the same as
+    /// 2^32 calls to [`jump`](#method.jump), so it can be used to hand out non-overlapping
+    /// ranges of 2^32 jumpable streams each, e.g. one per machine.
+    pub fn long_jump (&mut self) {
+        self.jump_by(LONG_JUMP);
+    }
+
+    /// Returns a new generator starting at the current state, with no cached values, and
+    /// [`jump`](#method.jump)s this generator ahead. Thus repeated calls hand out generators
+    /// whose next 2^64 values don't overlap with each other or with this generator.
+    pub fn split (&mut self) -> XorShift128Plus {
+        let result = XorShift128Plus::from_raw(self.s0, self.s1);
+
+        self.jump();
+
+        result
+    }
+
+    // Replaces the state with the state `polynomial` steps ahead, by summing the states along
+    // the way that correspond to the set coefficients.
+    pub(crate) fn jump_by (&mut self, polynomial: [u64; 2]) {
+        let mut rng = XorShift128Plus::from_raw(self.s0, self.s1);
+        let mut s0 = 0;
+        let mut s1 = 0;
+
+        for &word in polynomial.iter() {
+            for bit in 0..64 {
+                if (word >> bit) & 1 == 1 {
+                    s0 ^= rng.s0;
+                    s1 ^= rng.s1;
+                }
+
+                rng.next_u64();
+            }
+        }
+
+        self.s0 = s0;
+        self.s1 = s1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XorShift128Plus;
+
+    fn reference () -> XorShift128Plus {
+        XorShift128Plus::from_bytes([
+            0x5d, 0x28, 0x94, 0x50, 0xc8, 0x88, 0xf9, 0x9b,
+            0x5e, 0x5c, 0x1f, 0xd1, 0x35, 0x09, 0xe3, 0x9e,
+        ])
+    }
+
+    #[test]
+    fn it_should_jump() {
+        let mut rng = reference();
+
+        rng.jump();
+
+        assert_eq!(rng.draws(), 0);
+        assert_eq!(rng.next(), 0.3713157352558343);
+        assert_eq!(rng.next(), 0.10137260006327709);
+        assert_eq!(rng.next(), 0.8615474847223727);
+
+        let mut rng = reference();
+
+        rng.jump();
+        rng.jump();
+
+        assert_eq!(rng.next(), 0.8797924908202455);
+    }
+
+    #[test]
+    fn it_should_long_jump() {
+        let mut rng = reference();
+
+        rng.long_jump();
+
+        assert_eq!(rng.next(), 0.00788736711803506);
+        assert_eq!(rng.next(), 0.4262273728916737);
+        assert_eq!(rng.next(), 0.4297893274202016);
+    }
+
+    #[test]
+    fn it_should_split_into_non_overlapping_streams() {
+        let mut rng = reference();
+        let mut first = rng.split();
+        let mut second = rng.split();
+
+        assert_eq!(first.next(), 0.35873106038177727);
+        assert_eq!(second.next(), 0.3713157352558343);
+        assert_eq!(rng.next(), 0.8797924908202455);
+    }
+}
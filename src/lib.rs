@@ -1,24 +1,108 @@
+//! The `std` feature is enabled by default. Without it the crate is `no_std`, and only the
+//! parts that need neither an allocator nor floating point functions such as `ln` and `sqrt`
+//! are available, i.e. seeding, the uniform and integer outputs, and the helpers built on them.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(any(feature = "std", test))]
+extern crate core;
+#[cfg(feature = "rand_core")]
+extern crate rand_core;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
+mod adapters;
+mod batch;
+mod color;
+mod diagnostics;
+#[cfg(feature = "std")]
+mod distributions;
+mod fill;
+mod fuzz;
+mod generator;
+#[cfg(feature = "std")]
+mod geometry;
+#[cfg(feature = "std")]
+mod graph;
+mod hash;
+#[cfg(feature = "rand_core")]
+mod interop;
+#[cfg(feature = "std")]
+mod json;
+mod jump;
+#[cfg(feature = "std")]
+mod linalg;
+mod low_discrepancy;
+mod noise;
+mod park_miller;
+mod seq;
+#[cfg(feature = "std")]
+mod series;
+mod splitmix64;
+#[cfg(feature = "std")]
+mod text;
+mod time;
+mod variant;
+#[cfg(feature = "std")]
+mod ziggurat_tables;
+
+pub use adapters::{BudgetRng, Interleaved};
+#[cfg(feature = "std")]
+pub use adapters::{LoggedRng, RecordingRng};
+pub use diagnostics::{RunningStats, ALGORITHM_VERSION};
+#[cfg(feature = "std")]
+pub use distributions::Zipf;
+pub use fill::Fillable;
+pub use generator::{Algorithm, Generator};
+pub use hash::hash_to_f64;
+#[cfg(feature = "std")]
+pub use json::JsonValue;
+pub use low_discrepancy::Halton;
+#[cfg(feature = "std")]
+pub use noise::GradientNoise1D;
+pub use park_miller::ParkMiller;
+pub use seq::shuffle_with_seed;
+#[cfg(feature = "std")]
+pub use seq::CountTable;
+pub use splitmix64::SplitMix64;
+#[cfg(feature = "std")]
+pub use text::CharClass;
+pub use variant::RandomVariant;
+
+use core::ops::Range;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 const MANTISSA_BITS: i32 = 52;
 const MANTISSA_MASK: u64 = (1u64 << MANTISSA_BITS) - 1;
+const SPLITMIX64_GAMMA: u64 = 0x9E3779B97F4A7C15_u64;
 
+// Returns `x * 2^exp`, building the power of two directly from its bits, which requires `exp`
+// to be within the range of normal exponents.
 fn ldexp(x: u64, exp: i32) -> f64 {
-    (x as f64) * f64::exp2(exp as f64)
+    debug_assert!((-1022..=1023).contains(&exp));
+
+    (x as f64) * f64::from_bits(((exp + 1023) as u64) << 52)
 }
 
 // https://en.wikipedia.org/wiki/Lehmer_random_number_generator
-fn lcg_parkmiller(seed: u32) -> u32 {
+const fn lcg_parkmiller(seed: u32) -> u32 {
     (((seed as u64) * 48_271_u64) % 2_147_483_647_u64) as u32
 }
 
 // http://xorshift.di.unimi.it/splitmix64.c
-fn splitmix64(seed: u64) -> u64 {
-    let mut z = seed + 0x9E3779B97F4A7C15_u64;
-    z = (z ^ (z >> 30)) * 0xBF58476D1CE4E5B9_u64;
-    z = (z ^ (z >> 27)) * 0x94D049BB133111EB_u64;
-    return z ^ (z >> 31);
+const fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(SPLITMIX64_GAMMA);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9_u64);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB_u64);
+    z ^ (z >> 31)
 }
 
-fn u64_from_bytes (bytes: &[u8]) -> u64 {
+#[allow(clippy::identity_op)]
+const fn u64_from_bytes (bytes: &[u8]) -> u64 {
     ((bytes[7] as u64) << 56) + ((bytes[6] as u64) << 48) +
     ((bytes[5] as u64) << 40) + ((bytes[4] as u64) << 32) +
     ((bytes[3] as u64) << 24) + ((bytes[2] as u64) << 16) +
@@ -61,27 +145,64 @@ fn u64_from_bytes (bytes: &[u8]) -> u64 {
 /// println!("Second random float: {}", rng.next());
 /// # }
 /// ```
-pub struct XorShift128Plus (u64, u64);
+///
+/// Besides the 128 bits of xorshift state, the generator also remembers the previous output of
+/// [`next_smoothed`](#method.next_smoothed), so that it can keep a moving average between calls,
+/// and counts the number of steps taken, see [`draws`](#method.draws). With the `serde` feature,
+/// all of it can be serialized, e.g. to persist the generator alongside a saved game and resume
+/// exactly where it left off.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct XorShift128Plus {
+    s0: u64,
+    s1: u64,
+    // The last value returned by `next_smoothed`, or `None` if it hasn't been called yet
+    smoothed: Option<f64>,
+    // The number of raw steps taken since construction
+    draws: u64,
+}
 
 impl XorShift128Plus {
-    /// Constructs a new RNG with the seed specified as 16 bytes of raw data.
-    pub fn from_bytes (seed: [u8; 16]) -> XorShift128Plus {
-        XorShift128Plus (
-            u64_from_bytes(&seed[0..8]),
-            u64_from_bytes(&seed[8..16]),
-        )
+    const fn from_raw (s0: u64, s1: u64) -> XorShift128Plus {
+        XorShift128Plus { s0, s1, smoothed: None, draws: 0 }
+    }
+
+    /// Constructs a new RNG with the seed specified as 16 bytes of raw data. Like the other
+    /// seeding functions it's a `const fn`, so generators can be seeded at compile time. The new
+    /// generator has an empty [`next_smoothed`](#method.next_smoothed) cache and a
+    /// [`draws`](#method.draws) count of 0, even when `seed` comes from [`state`](#method.state).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate xorshift128plus;
+    ///
+    /// use xorshift128plus::XorShift128Plus;
+    ///
+    /// const LEVEL_SEED: XorShift128Plus = XorShift128Plus::from_bytes([7; 16]);
+    ///
+    /// # fn main() {
+    /// let mut rng = LEVEL_SEED;
+    ///
+    /// println!("First random float: {}", rng.next());
+    /// # }
+    /// ```
+    pub const fn from_bytes (seed: [u8; 16]) -> XorShift128Plus {
+        let (s0, s1) = seed.split_at(8);
+
+        XorShift128Plus::from_raw(u64_from_bytes(s0), u64_from_bytes(s1))
     }
 
     /// Constructs a new RNG with the seed specified as a unsigned 32bit integer. Note that
     /// this seeding is suboptimal since it will only contain 32 bits of entropy instead
     /// of 128 bits.
-    pub fn from_u32 (seed: u32) -> XorShift128Plus {
+    pub const fn from_u32 (seed: u32) -> XorShift128Plus {
         let raw0 = lcg_parkmiller(seed);
         let raw1 = lcg_parkmiller(raw0);
         let raw2 = lcg_parkmiller(raw1);
         let raw3 = lcg_parkmiller(raw2);
 
-        XorShift128Plus (
+        XorShift128Plus::from_raw(
             ((raw1 as u64) << 32) + (raw0 as u64),
             ((raw3 as u64) << 32) + (raw2 as u64),
         )
@@ -90,28 +211,180 @@ impl XorShift128Plus {
     /// Constructs a new RNG with the seed specified as a unsigned 64bit integer. Note that
     /// this seeding is suboptimal since it will only contain 64 bits of entropy instead
     /// of 128 bits.
-    pub fn from_u64 (seed: u64) -> XorShift128Plus {
+    pub const fn from_u64 (seed: u64) -> XorShift128Plus {
         let raw0 = splitmix64(seed);
         let raw1 = splitmix64(raw0);
 
-        XorShift128Plus (raw0, raw1)
+        XorShift128Plus::from_raw(raw0, raw1)
+    }
+
+    /// Constructs a new RNG by mixing the states of `a` and `b` with splitmix64, e.g. to seed a
+    /// master generator from several sources of entropy. Neither generator is advanced, and the
+    /// result never has the all-zero state, which would only ever produce zeros.
+    pub fn combine (a: &XorShift128Plus, b: &XorShift128Plus) -> XorShift128Plus {
+        let s0 = splitmix64(a.s0 ^ splitmix64(b.s0));
+        let s1 = splitmix64(a.s1 ^ splitmix64(b.s1 ^ s0));
+
+        if s0 == 0 && s1 == 0 {
+            XorShift128Plus::from_raw(0, SPLITMIX64_GAMMA)
+        } else {
+            XorShift128Plus::from_raw(s0, s1)
+        }
     }
 
     /// Returns the next psuedo-random number between 0 (inclusivly) and 1 (exclusivly).
+    #[allow(clippy::should_implement_trait)]
     pub fn next (&mut self) -> f64 {
-        let mut x = self.0;
-        let y = self.1;
+        ldexp(self.next_u64() & MANTISSA_MASK, -MANTISSA_BITS)
+    }
+
+    /// Returns the next psuedo-random number between 0 and 1 (both exclusivly), which is useful
+    /// when the value is passed to e.g. a logarithm. The values are spaced evenly, halfway
+    /// between the ones returned by [`next`](#method.next).
+    pub fn next_open (&mut self) -> f64 {
+        ldexp(((self.next_u64() & MANTISSA_MASK) << 1) + 1, -MANTISSA_BITS - 1)
+    }
+
+    /// Returns the next psuedo-random number between 0 (inclusivly) and 1 (exclusivly) as a 32bit
+    /// fixed-point fraction, i.e. the value represents `next_fixed() / 2^32`. It's derived
+    /// purely with integer operations, so unlike floats it is bit-for-bit identical on every
+    /// platform, which makes it suitable for e.g. lockstep networking.
+    pub fn next_fixed (&mut self) -> u32 {
+        self.next_u32()
+    }
+
+    /// Returns either 1 or -1 with equal probability, using the top bit of a step.
+    pub fn random_sign (&mut self) -> f64 {
+        if self.next_u64() >> 63 == 0 { 1.0 } else { -1.0 }
+    }
+
+    /// Returns the number of raw steps the generator has taken since it was constructed. Every
+    /// method that draws from the generator counts each step it takes, so this is useful for
+    /// auditing how much randomness a piece of code consumed.
+    pub fn draws (&self) -> u64 {
+        self.draws
+    }
+
+    /// Returns the 128 bits of xorshift state, in the same layout that
+    /// [`from_bytes`](#method.from_bytes) takes, so that `XorShift128Plus::from_bytes(rng.state())`
+    /// continues exactly where `rng` is, e.g. for checkpointing a simulation. Note that the
+    /// moving average of [`next_smoothed`](#method.next_smoothed) and the [`draws`](#method.draws)
+    /// count aren't included, use the `serde` feature to persist those as well. A generator
+    /// restored this way starts with an empty smoothing cache, as after
+    /// [`clear_cache`](#method.clear_cache), and with `draws` reset to 0.
+    pub fn state (&self) -> [u8; 16] {
+        let mut state = [0u8; 16];
+
+        state[0..8].copy_from_slice(&self.s0.to_le_bytes());
+        state[8..16].copy_from_slice(&self.s1.to_le_bytes());
+
+        state
+    }
+
+    /// Calls `accept` with the generator until it returns `true`, e.g. to skip past a rejection
+    /// sampling loop, and returns the number of raw steps that were taken along the way (as
+    /// counted by [`draws`](#method.draws)).
+    pub fn advance_past<F: FnMut(&mut Self) -> bool> (&mut self, mut accept: F) -> u64 {
+        let start = self.draws;
+
+        while !accept(self) {}
+
+        self.draws - start
+    }
+
+    /// Advances the generator `n` steps, discarding the output.
+    pub fn discard (&mut self, n: u64) {
+        for _ in 0..n {
+            self.next_u64();
+        }
+    }
+
+    /// Returns the next psuedo-random unsigned 64bit integer, i.e. the raw output of one step.
+    /// [`next`](#method.next) returns the lower 52 bits of the same output as a float.
+    pub fn next_u64 (&mut self) -> u64 {
+        self.draws += 1;
+
+        let mut x = self.s0;
+        let y = self.s1;
 
-        self.0 = y;
+        self.s0 = y;
 
         x ^= x << 23;
         x ^= x >> 17;
         x ^= y;
         x ^= y >> 26;
 
-        self.1 = x;
+        self.s1 = x;
 
-        ldexp(u64::wrapping_add(self.0, self.1) & MANTISSA_MASK, -MANTISSA_BITS)
+        u64::wrapping_add(self.s0, self.s1)
+    }
+
+    /// Returns the next psuedo-random unsigned 32bit integer, taken from the upper, and
+    /// statistically stronger, 32 bits of a step.
+    pub fn next_u32 (&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns an unbiased psuedo-random integer between 0 (inclusivly) and `bound`
+    /// (exclusivly), using Lemire's multiply-and-reject method. Unlike multiplying a float by
+    /// `bound` and truncating, every integer is exactly equally likely, and usually only a
+    /// single step is needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is 0.
+    pub fn next_below (&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "bound must be greater than 0");
+
+        let mut m = (self.next_u64() as u128) * (bound as u128);
+
+        if (m as u64) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+
+            while (m as u64) < threshold {
+                m = (self.next_u64() as u128) * (bound as u128);
+            }
+        }
+
+        (m >> 64) as u64
+    }
+
+    /// Returns an unbiased psuedo-random integer within `range`, i.e. between `range.start`
+    /// (inclusivly) and `range.end` (exclusivly), see [`next_below`](#method.next_below).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate xorshift128plus;
+    ///
+    /// use xorshift128plus::XorShift128Plus;
+    ///
+    /// # fn main() {
+    /// let mut rng = XorShift128Plus::from_u32(4293262078);
+    /// let roll = rng.gen_range(1..7);
+    ///
+    /// assert!((1..7).contains(&roll));
+    /// # }
+    /// ```
+    pub fn gen_range (&mut self, range: Range<u64>) -> u64 {
+        assert!(range.start < range.end, "range must not be empty");
+
+        range.start + self.next_below(range.end - range.start)
+    }
+
+    /// Fills `dest` with psuedo-random bytes, taking eight bytes in little-endian order from
+    /// each step, and discarding the leftover bytes of the final step.
+    pub fn fill_bytes (&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            let len = chunk.len();
+
+            chunk.copy_from_slice(&bytes[..len]);
+        }
     }
 }
 
@@ -133,6 +406,91 @@ mod tests {
         assert_eq!(rng.next(), 0.8974382234842848);
     }
 
+    #[test]
+    fn it_should_generate_integers() {
+        let mut rng = XorShift128Plus::from_bytes([
+            0x5d, 0x28, 0x94, 0x50, 0xc8, 0x88, 0xf9, 0x9b,
+            0x5e, 0x5c, 0x1f, 0xd1, 0x35, 0x09, 0xe3, 0x9e,
+        ]);
+
+        assert_eq!(rng.next_u64(), 0x0015bd5cc7c662db);
+        assert_eq!(rng.next_u32(), 0xc66be4c7);
+
+        let mut bytes = [0u8; 4];
+        rng.fill_bytes(&mut bytes);
+
+        assert_eq!(bytes, [0x6f, 0x13, 0x3a, 0x7a]);
+
+        let mut counts = [0u32; 6];
+
+        for _ in 0..12_000 {
+            let value = rng.gen_range(10..16);
+
+            assert!((10..16).contains(&value));
+            counts[(value - 10) as usize] += 1;
+
+            assert!(rng.next_below(3) < 3);
+        }
+
+        assert!(counts.iter().all(|&count| count > 1_850 && count < 2_150));
+        assert_eq!(rng.gen_range(5..6), 5);
+    }
+
+    #[test]
+    fn it_should_resume_from_its_state() {
+        let seed = [
+            0x5d, 0x28, 0x94, 0x50, 0xc8, 0x88, 0xf9, 0x9b,
+            0x5e, 0x5c, 0x1f, 0xd1, 0x35, 0x09, 0xe3, 0x9e,
+        ];
+
+        let mut rng = XorShift128Plus::from_bytes(seed);
+
+        assert_eq!(rng.state(), seed);
+
+        rng.discard(10);
+
+        let mut resumed = XorShift128Plus::from_bytes(rng.state());
+
+        for _ in 0..10 {
+            assert_eq!(resumed.next(), rng.next());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn it_should_serialize_the_full_generator() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        rng.next_smoothed(0.5);
+
+        let json = ::serde_json::to_string(&rng).unwrap();
+        let mut restored: XorShift128Plus = ::serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.draws(), 1);
+
+        for _ in 0..10 {
+            assert_eq!(restored.next_smoothed(0.5), rng.next_smoothed(0.5));
+        }
+    }
+
+    #[test]
+    fn it_should_seed_at_compile_time() {
+        const FROM_BYTES: XorShift128Plus = XorShift128Plus::from_bytes([
+            0x5d, 0x28, 0x94, 0x50, 0xc8, 0x88, 0xf9, 0x9b,
+            0x5e, 0x5c, 0x1f, 0xd1, 0x35, 0x09, 0xe3, 0x9e,
+        ]);
+        const FROM_U32: XorShift128Plus = XorShift128Plus::from_u32(4293262078);
+        const FROM_U64: XorShift128Plus = XorShift128Plus::from_u64(42);
+
+        let mut from_bytes = FROM_BYTES;
+        let mut from_u32 = FROM_U32;
+        let mut from_u64 = FROM_U64;
+
+        assert_eq!(from_bytes.next(), 0.35873106038177727);
+        assert_eq!(from_u32.next(), 0.4335893835472515);
+        assert_eq!(from_u64.next(), XorShift128Plus::from_u64(42).next());
+    }
+
     #[test]
     fn it_should_seed_from_u32() {
         let mut rng = XorShift128Plus::from_u32(4293262078);
@@ -143,4 +501,91 @@ mod tests {
         assert_eq!(rng.next(), 0.480991995797152);
         assert_eq!(rng.next(), 0.6796126170804464);
     }
+
+    #[test]
+    fn it_should_generate_fixed_point_fractions() {
+        let mut rng = XorShift128Plus::from_bytes([
+            0x5d, 0x28, 0x94, 0x50, 0xc8, 0x88, 0xf9, 0x9b,
+            0x5e, 0x5c, 0x1f, 0xd1, 0x35, 0x09, 0xe3, 0x9e,
+        ]);
+
+        assert_eq!(rng.next_fixed(), 1424732);
+        assert_eq!(rng.next_fixed(), 3328959687);
+        assert_eq!(rng.next_fixed(), 2091523801);
+        assert_eq!(rng.next_fixed(), 964384431);
+        assert_eq!(rng.next_fixed(), 1259232232);
+    }
+
+    #[test]
+    fn it_should_combine_generators() {
+        let a = XorShift128Plus::from_u32(1);
+        let b = XorShift128Plus::from_u32(2);
+
+        let mut combined = XorShift128Plus::combine(&a, &b);
+        let mut again = XorShift128Plus::combine(&a, &b);
+        let mut swapped = XorShift128Plus::combine(&b, &a);
+
+        let value = combined.next();
+
+        assert_eq!(value, again.next());
+        assert_ne!(value, swapped.next());
+        assert_ne!(value, a.clone().next());
+        assert_ne!(value, b.clone().next());
+
+        let zero = XorShift128Plus::from_bytes([0; 16]);
+        let combined = XorShift128Plus::combine(&zero, &zero);
+
+        assert!(combined.s0 != 0 || combined.s1 != 0);
+    }
+
+    #[test]
+    fn it_should_count_draws() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(rng.draws(), 0);
+
+        rng.next();
+        rng.next();
+        rng.next();
+        rng.discard(2);
+
+        assert_eq!(rng.draws(), 5);
+
+        let mut other = XorShift128Plus::from_u32(4293262078);
+        other.discard(5);
+
+        assert_eq!(rng.next(), other.next());
+    }
+
+    #[test]
+    fn it_should_count_draws_while_advancing() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut calls = 0;
+
+        rng.next();
+
+        let steps = rng.advance_past(|rng| {
+            calls += 1;
+            rng.next() > 0.9
+        });
+
+        assert_eq!(steps, calls);
+        assert_eq!(rng.draws(), calls + 1);
+
+        let mut other = XorShift128Plus::from_u32(4293262078);
+        other.discard(rng.draws());
+
+        assert_eq!(rng.next(), other.next());
+    }
+
+    #[test]
+    fn it_should_seed_from_u64() {
+        let mut rng = XorShift128Plus::from_u64(1234567);
+
+        assert_eq!(rng.next(), 0.7181375046825917);
+        assert_eq!(rng.next(), 0.7871863222760291);
+        assert_eq!(rng.next(), 0.10357868490567657);
+        assert_eq!(rng.next(), 0.8181865886464517);
+        assert_eq!(rng.next(), 0.7173886202474287);
+    }
 }
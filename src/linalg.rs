@@ -0,0 +1,85 @@
+use super::XorShift128Plus;
+
+impl XorShift128Plus {
+    /// Returns a `rows` by `cols` matrix of psuedo-random numbers between 0 (inclusivly) and 1
+    /// (exclusivly), as a vector of rows. It's filled in row-major order, so it holds the same
+    /// values as `rows * cols` sequential calls to [`next`](#method.next).
+    pub fn fill_matrix (&mut self, rows: usize, cols: usize) -> Vec<Vec<f64>> {
+        (0..rows).map(|_| (0..cols).map(|_| self.next()).collect()).collect()
+    }
+
+    /// Returns a psuedo-random `n` by `n` symmetric positive-definite matrix, as a vector of
+    /// rows, for testing linear solvers. It's computed as `A * Aᵀ + n * I` where `A` is filled
+    /// with standard normals, so it's always symmetric and well away from singular.
+    pub fn random_spd (&mut self, n: usize) -> Vec<Vec<f64>> {
+        let a = (0..n)
+            .map(|_| (0..n).map(|_| self.next_normal_ziggurat()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        a.iter().enumerate().map(|(i, row_i)| {
+            a.iter().enumerate().map(|(j, row_j)| {
+                let dot = row_i.iter().zip(row_j).map(|(x, y)| x * y).sum::<f64>();
+
+                if i == j { dot + n as f64 } else { dot }
+            }).collect()
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XorShift128Plus;
+
+    #[test]
+    fn it_should_fill_matrices_in_row_major_order() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        let matrix = a.fill_matrix(2, 3);
+
+        assert_eq!(matrix.len(), 2);
+
+        for row in matrix.iter() {
+            assert_eq!(row.len(), 3);
+
+            for &value in row.iter() {
+                assert_eq!(value, b.next());
+            }
+        }
+
+        let mut c = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(matrix, c.fill_matrix(2, 3));
+    }
+
+    #[test]
+    fn it_should_generate_positive_definite_matrices() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let n = 8;
+        let matrix = rng.random_spd(n);
+
+        assert_eq!(matrix.len(), n);
+
+        // A Cholesky decomposition only succeeds for symmetric positive-definite matrices
+        let mut lower = vec![vec![0.0; n]; n];
+
+        for i in 0..n {
+            assert_eq!(matrix[i].len(), n);
+
+            for (j, row) in matrix.iter().enumerate() {
+                assert_eq!(matrix[i][j], row[i]);
+            }
+
+            for j in 0..=i {
+                let sum = (0..j).map(|k| lower[i][k] * lower[j][k]).sum::<f64>();
+
+                if i == j {
+                    assert!(matrix[i][i] - sum > 0.0);
+                    lower[i][i] = (matrix[i][i] - sum).sqrt();
+                } else {
+                    lower[i][j] = (matrix[i][j] - sum) / lower[j][j];
+                }
+            }
+        }
+    }
+}
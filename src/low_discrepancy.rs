@@ -0,0 +1,212 @@
+use super::{ldexp, XorShift128Plus, MANTISSA_BITS};
+
+/// The Halton sequence in a single dimension, i.e. the van der Corput sequence in the given
+/// base. It's not random at all, but covers the unit interval more evenly than random numbers
+/// do, which makes it useful for quasi-Monte Carlo methods and for comparing against
+/// [`XorShift128Plus`](struct.XorShift128Plus.html).
+///
+/// # Examples
+///
+/// ```
+/// extern crate xorshift128plus;
+///
+/// use xorshift128plus::Halton;
+///
+/// # fn main() {
+/// let mut halton = Halton::new(2);
+///
+/// assert_eq!(halton.next(), 0.5);
+/// assert_eq!(halton.next(), 0.25);
+/// assert_eq!(halton.next(), 0.75);
+/// # }
+/// ```
+pub struct Halton {
+    index: u64,
+    base: u32,
+}
+
+impl Halton {
+    /// Constructs a new sequence in the given base, usually a prime, starting from index 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is less than 2.
+    pub fn new (base: u32) -> Halton {
+        assert!(base >= 2, "base must be at least 2");
+
+        Halton { index: 1, base }
+    }
+
+    /// Returns the next number of the sequence, between 0 and 1 (both exclusivly).
+    #[allow(clippy::should_implement_trait)]
+    pub fn next (&mut self) -> f64 {
+        let result = radical_inverse(self.index, self.base);
+        self.index += 1;
+        result
+    }
+}
+
+impl XorShift128Plus {
+    /// Returns `n` psuedo-random numbers between 0 (inclusivly) and 1 (exclusivly), one in each
+    /// of the `n` equal strata of the unit interval, in ascending order. The `i`th value is
+    /// `(i + next()) / n`, which covers the interval more evenly than independent draws.
+    #[cfg(feature = "std")]
+    pub fn stratified (&mut self, n: usize) -> Vec<f64> {
+        (0..n).map(|i| {
+            let value = (i as f64 + self.next()) / n as f64;
+
+            // Rounding may otherwise push the value up into the next stratum
+            value.min(((i + 1) as f64 / n as f64).next_down())
+        }).collect()
+    }
+
+    /// Returns `rows * cols` psuedo-random points in the unit square, one jittered point in each
+    /// cell of a `rows` by `cols` grid, in row-major order. The point of cell `(i, j)` is
+    /// `((j + next()) / cols, (i + next()) / rows)`, which covers the square more evenly than
+    /// independent draws.
+    #[cfg(feature = "std")]
+    pub fn jittered_grid (&mut self, rows: usize, cols: usize) -> Vec<(f64, f64)> {
+        let mut points = Vec::with_capacity(rows * cols);
+
+        for i in 0..rows {
+            for j in 0..cols {
+                let x = (j as f64 + self.next()) / cols as f64;
+                let y = (i as f64 + self.next()) / rows as f64;
+
+                // Rounding may otherwise push the point up into the next cell
+                points.push((
+                    x.min(((j + 1) as f64 / cols as f64).next_down()),
+                    y.min(((i + 1) as f64 / rows as f64).next_down()),
+                ));
+            }
+        }
+
+        points
+    }
+
+    /// Returns the point at `index` of the first two dimensions of the Sobol sequence, with
+    /// random-digit scrambling, as two numbers between 0 (inclusivly) and 1 (exclusivly). The
+    /// scramble is derived from the current state without advancing it, so every index of a run
+    /// shares it and the points stay evenly spread. Advance the generator, e.g. with
+    /// [`next`](#method.next), to start a new randomized run.
+    pub fn scrambled_pair (&self, index: u64) -> (f64, f64) {
+        let mut scramble = XorShift128Plus::from_raw(self.s0, self.s1);
+
+        // The first dimension is the van der Corput sequence, the second uses the direction
+        // numbers of the primitive polynomial x + 1
+        let x = index.reverse_bits() ^ scramble.next_u64();
+        let mut y = scramble.next_u64();
+        let mut direction = 1u64 << 63;
+        let mut bits = index;
+
+        while bits > 0 {
+            if bits & 1 == 1 {
+                y ^= direction;
+            }
+
+            bits >>= 1;
+            direction ^= direction >> 1;
+        }
+
+        (ldexp(x >> 12, -MANTISSA_BITS), ldexp(y >> 12, -MANTISSA_BITS))
+    }
+}
+
+// Mirrors the digits of `index` in `base` around the decimal point.
+fn radical_inverse (mut index: u64, base: u32) -> f64 {
+    let base = base as u64;
+    let inverse_base = 1.0 / base as f64;
+
+    let mut result = 0.0;
+    let mut scale = inverse_base;
+
+    while index > 0 {
+        result += (index % base) as f64 * scale;
+        index /= base;
+        scale *= inverse_base;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Halton, XorShift128Plus};
+
+    #[test]
+    fn it_should_generate_the_halton_sequence() {
+        let mut halton = Halton::new(2);
+
+        assert_eq!(halton.next(), 0.5);
+        assert_eq!(halton.next(), 0.25);
+        assert_eq!(halton.next(), 0.75);
+        assert_eq!(halton.next(), 0.125);
+        assert_eq!(halton.next(), 0.625);
+        assert_eq!(halton.next(), 0.375);
+        assert_eq!(halton.next(), 0.875);
+
+        let mut halton = Halton::new(3);
+
+        assert!((halton.next() - 1.0 / 3.0).abs() < 1e-15);
+        assert!((halton.next() - 2.0 / 3.0).abs() < 1e-15);
+        assert!((halton.next() - 1.0 / 9.0).abs() < 1e-15);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_generate_stratified_samples() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for &n in [1, 3, 10, 1000].iter() {
+            let samples = rng.stratified(n);
+
+            assert_eq!(samples.len(), n);
+
+            for (i, &sample) in samples.iter().enumerate() {
+                assert!(sample >= i as f64 / n as f64);
+                assert!(sample < (i + 1) as f64 / n as f64);
+            }
+        }
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.stratified(10), b.stratified(10));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_generate_jittered_grids() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let points = rng.jittered_grid(7, 5);
+        let mut cells = [[0u32; 5]; 7];
+
+        assert_eq!(points.len(), 35);
+
+        for &(x, y) in points.iter() {
+            assert!((0.0..1.0).contains(&x) && (0.0..1.0).contains(&y));
+            cells[(y * 7.0) as usize][(x * 5.0) as usize] += 1;
+        }
+
+        assert!(cells.iter().all(|row| row.iter().all(|&count| count == 1)));
+    }
+
+    #[test]
+    fn it_should_generate_scrambled_pairs() {
+        let rng = XorShift128Plus::from_u32(4293262078);
+        let mut cells = [[0u32; 16]; 16];
+
+        for index in 0..1024 {
+            let (x, y) = rng.scrambled_pair(index);
+
+            assert!((0.0..1.0).contains(&x) && (0.0..1.0).contains(&y));
+            cells[(x * 16.0) as usize][(y * 16.0) as usize] += 1;
+        }
+
+        // Scrambling keeps the net property, so every cell gets exactly its share
+        assert!(cells.iter().all(|row| row.iter().all(|&count| count == 4)));
+
+        assert_eq!(rng.scrambled_pair(7), XorShift128Plus::from_u32(4293262078).scrambled_pair(7));
+        assert_ne!(rng.scrambled_pair(7), XorShift128Plus::from_u32(1).scrambled_pair(7));
+    }
+}
@@ -0,0 +1,175 @@
+use super::XorShift128Plus;
+
+#[cfg(feature = "std")]
+const GRADIENT_COUNT: usize = 256;
+
+/// One-dimensional gradient (Perlin-style) noise, e.g. for terrain profiles. A psuedo-random
+/// gradient is drawn for each of 256 integer lattice points, after which the field repeats, and
+/// samples blend the two neighbouring gradients with a smoothstep. The field is continuous, 0 at
+/// every integer and between -1 and 1 everywhere.
+///
+/// # Examples
+///
+/// ```
+/// extern crate xorshift128plus;
+///
+/// use xorshift128plus::{GradientNoise1D, XorShift128Plus};
+///
+/// # fn main() {
+/// let mut rng = XorShift128Plus::from_u32(4293262078);
+/// let noise = GradientNoise1D::new(&mut rng);
+///
+/// println!("Height at 3.7: {}", noise.sample(3.7));
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub struct GradientNoise1D {
+    gradients: [f64; GRADIENT_COUNT],
+}
+
+#[cfg(feature = "std")]
+impl GradientNoise1D {
+    /// Constructs a new noise field with gradients between -1 (inclusivly) and 1 (exclusivly)
+    /// drawn from `rng`.
+    pub fn new (rng: &mut XorShift128Plus) -> GradientNoise1D {
+        let mut gradients = [0.0; GRADIENT_COUNT];
+
+        for gradient in gradients.iter_mut() {
+            *gradient = rng.next() * 2.0 - 1.0;
+        }
+
+        GradientNoise1D { gradients }
+    }
+
+    /// Returns the value of the noise field at `x`.
+    pub fn sample (&self, x: f64) -> f64 {
+        let cell = x.floor();
+        let t = x - cell;
+        let i = cell.rem_euclid(GRADIENT_COUNT as f64) as usize;
+
+        let left = self.gradients[i] * t;
+        let right = self.gradients[(i + 1) % GRADIENT_COUNT] * (t - 1.0);
+        let smooth = t * t * (3.0 - 2.0 * t);
+
+        // Blending two ramps of slope at most 1 peaks at 0.5, so scale up to cover [-1, 1]
+        2.0 * (left + smooth * (right - left))
+    }
+}
+
+impl XorShift128Plus {
+    /// Returns the next psuedo-random number between 0 (inclusivly) and 1 (exclusivly), smoothed
+    /// as an exponential moving average of uniform draws. Each call returns `previous * smoothing +
+    /// next() * (1 - smoothing)`, so a `smoothing` close to 1 yields slowly drifting values while
+    /// 0 yields plain uniform draws. The first call returns a plain draw.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `smoothing` is not between 0 and 1 (inclusivly).
+    pub fn next_smoothed (&mut self, smoothing: f64) -> f64 {
+        assert!((0.0..=1.0).contains(&smoothing), "smoothing must be between 0 and 1");
+
+        let value = self.next();
+        let value = match self.smoothed {
+            Some(previous) => previous * smoothing + value * (1.0 - smoothing),
+            None => value,
+        };
+
+        self.smoothed = Some(value);
+        value
+    }
+
+    /// Drops any value cached between calls, so that a cloned generator continues exactly like a
+    /// freshly seeded one with the same state would. Currently the only such value is the moving
+    /// average kept by [`next_smoothed`](#method.next_smoothed); the normal samplers don't cache
+    /// the second value of the Box-Muller transform. Newly constructed generators start with an
+    /// empty cache, including ones restored with `XorShift128Plus::from_bytes(rng.state())`, so
+    /// restoring a generator from its [`state`](#method.state) clears the cache automatically.
+    pub fn clear_cache (&mut self) {
+        self.smoothed = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use super::GradientNoise1D;
+    use super::XorShift128Plus;
+
+    #[test]
+    fn it_should_smooth_values() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut previous = rng.next_smoothed(0.95);
+
+        for _ in 0..1000 {
+            let value = rng.next_smoothed(0.95);
+
+            assert!((0.0..1.0).contains(&value));
+            assert!((value - previous).abs() < 0.05);
+
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn it_should_not_smooth_with_zero_smoothing() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_smoothed(0.0), b.next());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_match_a_fresh_generator_after_clearing_the_cache() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut fresh = XorShift128Plus::from_u32(4293262078);
+
+        rng.next_bivariate_normal((0.0, 0.0), (1.0, 1.0), 0.5);
+        rng.next_smoothed(0.5);
+        fresh.discard(rng.draws());
+
+        let mut clone = rng.clone();
+        clone.clear_cache();
+
+        for _ in 0..10 {
+            let value = clone.next_smoothed(0.5);
+
+            assert_eq!(value, fresh.next_smoothed(0.5));
+            assert_ne!(value, rng.next_smoothed(0.5));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_generate_gradient_noise() {
+        let noise = GradientNoise1D::new(&mut XorShift128Plus::from_u32(4293262078));
+        let again = GradientNoise1D::new(&mut XorShift128Plus::from_u32(4293262078));
+
+        for i in -300..300 {
+            let x = i as f64;
+
+            assert_eq!(noise.sample(x), 0.0);
+
+            // Continuous across cell boundaries
+            assert!(noise.sample(x - 1e-9).abs() < 1e-8);
+            assert!(noise.sample(x + 1e-9).abs() < 1e-8);
+        }
+
+        let mut previous = noise.sample(-50.0);
+
+        for i in 1..10_000 {
+            let x = -50.0 + i as f64 * 0.01;
+            let value = noise.sample(x);
+
+            assert!((-1.0..=1.0).contains(&value));
+            assert!((value - previous).abs() < 0.04);
+            assert_eq!(value, again.sample(x));
+
+            previous = value;
+        }
+
+        assert_eq!(noise.sample(3.75), noise.sample(3.75 + 256.0));
+    }
+}
@@ -0,0 +1,78 @@
+use super::lcg_parkmiller;
+
+const MODULUS: u32 = 2_147_483_647;
+
+/// The Park-Miller "minimal standard" Lehmer generator (with multiplier 48271), as used
+/// internally for seeding from a `u32`. It has a period of only 2^31 - 2 and weak statistical
+/// properties, so it's mostly useful for reproducing the output of legacy systems.
+///
+/// # Examples
+///
+/// ```
+/// extern crate xorshift128plus;
+///
+/// use xorshift128plus::ParkMiller;
+///
+/// # fn main() {
+/// let mut rng = ParkMiller::new(1);
+///
+/// assert_eq!(rng.next_u32(), 48271);
+/// assert_eq!(rng.next_u32(), 182605794);
+/// # }
+/// ```
+pub struct ParkMiller (u32);
+
+impl ParkMiller {
+    /// Constructs a new generator from `seed`. Since 0 is a fixed point of the generator, a
+    /// seed of 0 (or any other multiple of 2^31 - 1) is replaced with 1.
+    pub fn new (seed: u32) -> ParkMiller {
+        match seed % MODULUS {
+            0 => ParkMiller (1),
+            seed => ParkMiller (seed),
+        }
+    }
+
+    /// Returns the next psuedo-random number between 1 (inclusivly) and 2^31 - 1 (exclusivly).
+    pub fn next_u32 (&mut self) -> u32 {
+        self.0 = lcg_parkmiller(self.0);
+        self.0
+    }
+
+    /// Returns the next psuedo-random number between 0 and 1 (both exclusivly).
+    pub fn next_f64 (&mut self) -> f64 {
+        (self.next_u32() as f64) / (MODULUS as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParkMiller;
+
+    #[test]
+    fn it_should_generate_the_minimal_standard_sequence() {
+        let mut rng = ParkMiller::new(1);
+
+        assert_eq!(rng.next_u32(), 48271);
+        assert_eq!(rng.next_u32(), 182605794);
+        assert_eq!(rng.next_u32(), 1291394886);
+        assert_eq!(rng.next_u32(), 1914720637);
+        assert_eq!(rng.next_u32(), 2078669041);
+
+        // The 10000th value from seed 1, as required of `std::minstd_rand` in C++
+        let mut rng = ParkMiller::new(1);
+
+        for _ in 1..10_000 {
+            rng.next_u32();
+        }
+
+        assert_eq!(rng.next_u32(), 399268537);
+    }
+
+    #[test]
+    fn it_should_not_get_stuck_on_zero() {
+        let mut rng = ParkMiller::new(0);
+
+        assert_eq!(rng.next_u32(), 48271);
+        assert!(rng.next_f64() > 0.0);
+    }
+}
@@ -0,0 +1,916 @@
+#[cfg(feature = "std")]
+use std::collections::{BTreeSet, VecDeque};
+
+use super::XorShift128Plus;
+
+/// A sampler for picking indices proportionally to integer counts, such as the weights of a loot
+/// table. Sampling uses integer arithmetic only, so the results are exactly reproducible across
+/// platforms.
+///
+/// # Examples
+///
+/// ```
+/// extern crate xorshift128plus;
+///
+/// use xorshift128plus::{CountTable, XorShift128Plus};
+///
+/// # fn main() {
+/// let mut rng = XorShift128Plus::from_u32(4293262078);
+/// let table = CountTable::from_counts(&[70, 25, 5]);
+///
+/// println!("Dropped item: {}", table.sample(&mut rng));
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub struct CountTable {
+    cumulative: Vec<u64>,
+}
+
+#[cfg(feature = "std")]
+impl CountTable {
+    /// Constructs a new table where index `i` is sampled with probability `counts[i]` divided by
+    /// the sum of all counts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all counts are 0.
+    pub fn from_counts (counts: &[u32]) -> CountTable {
+        let mut total = 0;
+        let mut cumulative = Vec::with_capacity(counts.len());
+
+        for &count in counts {
+            total += count as u64;
+            cumulative.push(total);
+        }
+
+        assert!(total > 0, "at least one count must be greater than 0");
+
+        CountTable { cumulative }
+    }
+
+    /// Returns a psuedo-random index, chosen proportionally to its count.
+    pub fn sample (&self, rng: &mut XorShift128Plus) -> usize {
+        let total = self.cumulative[self.cumulative.len() - 1];
+        let target = rng.next_below(total);
+
+        self.cumulative.partition_point(|&c| c <= target)
+    }
+}
+
+/// Shuffles `slice` in place with the Fisher-Yates algorithm, using a temporary generator
+/// seeded with `seed`. The same slice and seed always give the same permutation, which is
+/// convenient for one-off deterministic shuffles.
+///
+/// # Examples
+///
+/// ```
+/// extern crate xorshift128plus;
+///
+/// use xorshift128plus::shuffle_with_seed;
+///
+/// # fn main() {
+/// let mut players = ["alice", "bob", "carol", "dave"];
+/// shuffle_with_seed(&mut players, [7; 16]);
+///
+/// println!("Turn order: {:?}", players);
+/// # }
+/// ```
+pub fn shuffle_with_seed<T> (slice: &mut [T], seed: [u8; 16]) {
+    XorShift128Plus::from_bytes(seed).shuffle(slice);
+}
+
+impl XorShift128Plus {
+    /// Returns a psuedo-random index between 0 (inclusivly) and `len` (exclusivly), where each
+    /// index `i` is chosen proportionally to `weight(i)`. The closure is called twice for every
+    /// index, once to compute the total weight and once to find the chosen index, so it should
+    /// return the same value each time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is 0, if any weight is negative or not finite, or if all weights are 0.
+    pub fn weighted_index_by<F: Fn(usize) -> f64> (&mut self, len: usize, weight: F) -> usize {
+        assert!(len > 0, "len must be greater than 0");
+
+        let mut total = 0.0;
+
+        for i in 0..len {
+            let w = weight(i);
+            assert!(w >= 0.0 && w.is_finite(), "weights must be non-negative and finite");
+            total += w;
+        }
+
+        assert!(total > 0.0, "at least one weight must be greater than 0");
+
+        let target = self.next() * total;
+        let mut cumulative = 0.0;
+        let mut last = 0;
+
+        for i in 0..len {
+            let w = weight(i);
+
+            if w > 0.0 {
+                cumulative += w;
+                last = i;
+
+                if target < cumulative {
+                    return i;
+                }
+            }
+        }
+
+        // Rounding errors can leave `target` just above the final cumulative sum
+        last
+    }
+
+    /// Returns a psuedo-random element of `items`, where each element is chosen proportionally
+    /// to the weight at the same index of `weights`, see
+    /// [`weighted_index_by`](#method.weighted_index_by).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `items` and `weights` differ in length or are empty, if any weight is negative
+    /// or not finite, or if all weights are 0.
+    pub fn choose_weighted<'a, T> (&mut self, items: &'a [T], weights: &[f64]) -> &'a T {
+        assert_eq!(items.len(), weights.len(), "items and weights must have the same length");
+
+        &items[self.weighted_index_by(weights.len(), |i| weights[i])]
+    }
+
+    /// Returns a psuedo-random label from `table`, where each label is chosen with its paired
+    /// probability, using a single cumulative draw. A label with probability 0 is never chosen.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any probability is negative or not finite, or if the probabilities don't sum to
+    /// 1 within `1e-9`.
+    pub fn weighted_label<'a> (&mut self, table: &'a [(&'a str, f64)]) -> &'a str {
+        let total = table.iter().map(|&(_, p)| {
+            assert!(p >= 0.0 && p.is_finite(), "probabilities must be non-negative and finite");
+            p
+        }).sum::<f64>();
+
+        assert!((total - 1.0).abs() < 1e-9, "probabilities must sum to 1");
+
+        let target = self.next() * total;
+        let mut cumulative = 0.0;
+        let mut last = "";
+
+        for &(label, p) in table {
+            if p > 0.0 {
+                cumulative += p;
+                last = label;
+
+                if target < cumulative {
+                    return label;
+                }
+            }
+        }
+
+        // Rounding errors can leave `target` just above the final cumulative sum
+        last
+    }
+
+    /// Returns a psuedo-random index into `log_weights`, where each index `i` is chosen
+    /// proportionally to `exp(log_weights[i])`. It uses the Gumbel-max trick, returning the
+    /// index with the largest log-weight plus Gumbel noise, so the weights are never
+    /// exponentiated and can't underflow. A log-weight of negative infinity is never chosen.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `log_weights` is empty, if any log-weight is NaN or positive infinity, or if all
+    /// of them are negative infinity.
+    #[cfg(feature = "std")]
+    pub fn sample_log_weights (&mut self, log_weights: &[f64]) -> usize {
+        let mut best = None;
+        let mut best_key = f64::NEG_INFINITY;
+
+        for (i, &log_weight) in log_weights.iter().enumerate() {
+            assert!(log_weight < f64::INFINITY, "log-weights must not be NaN or infinity");
+
+            let key = log_weight - (-self.next_open().ln()).ln();
+
+            if best.is_none() || key > best_key {
+                best = Some(i);
+                best_key = key;
+            }
+        }
+
+        assert!(best_key > f64::NEG_INFINITY, "at least one log-weight must be finite");
+
+        best.unwrap()
+    }
+
+    /// Returns an endless iterator of unbiased psuedo-random integers between 0 (inclusivly) and
+    /// `bound` (exclusivly), drawn lazily from this generator using Lemire's method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is 0.
+    pub fn below_iter (&mut self, bound: u64) -> impl Iterator<Item = u64> + '_ {
+        assert!(bound > 0, "bound must be greater than 0");
+
+        core::iter::repeat_with(move || self.next_below(bound))
+    }
+
+    /// Returns an unbiased psuedo-random integer between 0 (inclusivly) and `bound`
+    /// (exclusivly) that is never `except`, e.g. a random neighbour other than the current node.
+    /// It draws below `bound - 1` and shifts values from `except` and up by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is less than 2, or if `except` is not less than `bound`.
+    pub fn next_below_except (&mut self, bound: u64, except: u64) -> u64 {
+        assert!(bound >= 2, "bound must be at least 2");
+        assert!(except < bound, "except must be less than bound");
+
+        let value = self.next_below(bound - 1);
+
+        if value >= except { value + 1 } else { value }
+    }
+
+    /// Returns an unbiased psuedo-random residue between 0 (inclusivly) and `modulus`
+    /// (exclusivly), using Lemire's method, e.g. for (non-cryptographic) modular arithmetic
+    /// test vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    pub fn next_mod (&mut self, modulus: u64) -> u64 {
+        assert!(modulus >= 1, "modulus must be at least 1");
+
+        self.next_below(modulus)
+    }
+
+    /// Returns a psuedo-random residue between 0 (inclusivly) and `modulus` (exclusivly) that
+    /// is coprime to `modulus`, i.e. a uniformly chosen unit of the ring, by redrawing
+    /// [`next_mod`](#method.next_mod) until the greatest common divisor is 1. Note that a
+    /// `modulus` of 1 always gives 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0.
+    pub fn next_coprime (&mut self, modulus: u64) -> u64 {
+        loop {
+            let value = self.next_mod(modulus);
+
+            if gcd(value, modulus) == 1 {
+                return value;
+            }
+        }
+    }
+
+    /// Returns a psuedo-random subset of `n` elements as a bitmask, where each bit `i` below `n`
+    /// is independently set with probability `p`. Bits from `n` and up are always 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than 64, or if `p` is not between 0 and 1 (inclusivly).
+    pub fn random_subset_mask (&mut self, n: u32, p: f64) -> u64 {
+        assert!(n <= 64, "n must not be greater than 64");
+        assert!((0.0..=1.0).contains(&p), "p must be between 0 and 1");
+
+        let mut mask = 0;
+
+        for i in 0..n {
+            if self.next() < p {
+                mask |= 1 << i;
+            }
+        }
+
+        mask
+    }
+
+    /// Returns a uniformly chosen element among the first `len` elements of `iter`, skipping
+    /// ahead to it without collecting the elements in between. Returns `None` if `len` is 0, or
+    /// if the iterator ends before the chosen position.
+    pub fn choose_from_iter<T> (&mut self, mut iter: impl Iterator<Item = T>, len: usize) -> Option<T> {
+        if len == 0 {
+            return None;
+        }
+
+        iter.nth(self.next_below(len as u64) as usize)
+    }
+
+    /// Returns a psuedo-random range `(start, end)` within `0..len`, i.e. with `start <= end
+    /// <= len`, chosen uniformly among all such ranges including empty ones, e.g. for random text
+    /// edits. Returns `(0, 0)` if `len` is 0.
+    pub fn next_subrange (&mut self, len: usize) -> (usize, usize) {
+        // Picking two distinct boundaries among `len + 2` gives each range exactly once
+        let [a, b] = self.sample_array::<2>(len + 2);
+
+        (a.min(b), a.max(b) - 1)
+    }
+
+    /// Returns `k` distinct psuedo-random indices between 0 (inclusivly) and `n` (exclusivly),
+    /// in ascending order. It uses Floyd's algorithm, so it takes time proportional to
+    /// `k * log(k)` regardless of `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than `n`.
+    #[cfg(feature = "std")]
+    pub fn sorted_sample (&mut self, n: usize, k: usize) -> Vec<usize> {
+        assert!(k <= n, "cannot sample more indices than there are");
+
+        let mut selected = BTreeSet::new();
+
+        for j in n - k..n {
+            let t = self.next_below(j as u64 + 1) as usize;
+
+            if !selected.insert(t) {
+                selected.insert(j);
+            }
+        }
+
+        selected.into_iter().collect()
+    }
+
+    /// Returns a bootstrap resample of `data`, i.e. `data.len()` elements drawn uniformly with
+    /// replacement. Each element is left out of a resample with probability `(1 - 1/n)^n`,
+    /// which approaches `1/e` for large inputs.
+    #[cfg(feature = "std")]
+    pub fn bootstrap_resample<T: Clone> (&mut self, data: &[T]) -> Vec<T> {
+        (0..data.len()).map(|_| data[self.next_below(data.len() as u64) as usize].clone()).collect()
+    }
+
+    /// Returns references to `k` distinct psuedo-random elements of `slice`, in their original
+    /// order, e.g. for sampling log lines to display. The indices are chosen with
+    /// [`sorted_sample`](#method.sorted_sample).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the length of `slice`.
+    #[cfg(feature = "std")]
+    pub fn sample_ordered<'a, T> (&mut self, slice: &'a [T], k: usize) -> Vec<&'a T> {
+        self.sorted_sample(slice.len(), k).into_iter().map(|i| &slice[i]).collect()
+    }
+
+    /// Returns a psuedo-random element of `slice` whose index isn't in `recent`, e.g. for
+    /// shuffling a playlist without repeats. The chosen index is pushed onto `recent`, which is
+    /// then trimmed to the last `window` indices, so passing the same deque to every call avoids
+    /// repeating any of the last `window` choices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` doesn't have more than `window` elements.
+    #[cfg(feature = "std")]
+    pub fn choose_no_recent<'a, T> (&mut self, slice: &'a [T], recent: &mut VecDeque<usize>, window: usize) -> &'a T {
+        assert!(slice.len() > window, "slice must have more elements than window");
+
+        while recent.len() > window {
+            recent.pop_front();
+        }
+
+        let index = loop {
+            let index = self.next_below(slice.len() as u64) as usize;
+
+            if !recent.contains(&index) {
+                break index;
+            }
+        };
+
+        recent.push_back(index);
+
+        if recent.len() > window {
+            recent.pop_front();
+        }
+
+        &slice[index]
+    }
+
+    /// Shuffles `slice` in place with the Fisher-Yates algorithm, using unbiased integer draws
+    /// so that every permutation is equally likely. The same seed always gives the same
+    /// permutation.
+    pub fn shuffle<T> (&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            slice.swap(i, self.next_below(i as u64 + 1) as usize);
+        }
+    }
+
+    /// Rotates `slice` left in place by a psuedo-random offset between 0 (inclusivly) and its
+    /// length (exclusivly), e.g. to give round-robin scheduling a random starting phase. This is
+    /// cheaper than a full shuffle, since it only draws a single number.
+    pub fn rotate_random<T> (&mut self, slice: &mut [T]) {
+        if !slice.is_empty() {
+            let offset = self.next_below(slice.len() as u64) as usize;
+            slice.rotate_left(offset);
+        }
+    }
+
+    /// Fills `out` with a psuedo-random permutation of `0..len`, shuffled with the unbiased
+    /// Fisher-Yates algorithm. Any previous contents are cleared, but the capacity is reused, so
+    /// repeated calls with the same buffer don't allocate once it's large enough.
+    #[cfg(feature = "std")]
+    pub fn shuffle_indexed (&mut self, len: usize, out: &mut Vec<usize>) {
+        out.clear();
+        out.extend(0..len);
+        self.shuffle(out);
+    }
+
+    /// Returns a psuedo-random permutation of `0..n`, shuffled with the unbiased Fisher-Yates
+    /// algorithm. Since any order is a valid topological order of `n` tasks without
+    /// dependencies, this is a convenient way of randomizing task order in scheduler tests.
+    #[cfg(feature = "std")]
+    pub fn random_topo_order (&mut self, n: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..n).collect();
+        self.shuffle(&mut order);
+        order
+    }
+
+    /// Returns a psuedo-randomly shuffled deck of 52 playing cards, where each card is encoded
+    /// as `suit * 13 + rank`, with both `suit` and `rank` counting from 0.
+    pub fn shuffled_deck (&mut self) -> [u8; 52] {
+        let mut deck = [0u8; 52];
+
+        for (i, card) in deck.iter_mut().enumerate() {
+            *card = i as u8;
+        }
+
+        self.shuffle(&mut deck);
+        deck
+    }
+
+    /// Returns `K` distinct psuedo-random indices between 0 (inclusivly) and `n` (exclusivly),
+    /// in no particular order. It uses Floyd's algorithm on the stack, so it doesn't allocate,
+    /// but it takes time proportional to `K * K` and is thus meant for small `K`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is greater than `n`.
+    pub fn sample_array<const K: usize> (&mut self, n: usize) -> [usize; K] {
+        assert!(K <= n, "cannot sample more indices than there are");
+
+        let mut result = [0; K];
+
+        for (i, j) in (n - K..n).enumerate() {
+            let t = self.next_below(j as u64 + 1) as usize;
+            result[i] = if result[..i].contains(&t) { j } else { t };
+        }
+
+        result
+    }
+}
+
+// Returns the greatest common divisor of `a` and `b` with Euclid's algorithm.
+fn gcd (mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use std::collections::VecDeque;
+
+    #[cfg(feature = "std")]
+    use super::CountTable;
+    use super::{gcd, shuffle_with_seed, XorShift128Plus};
+
+    #[test]
+    fn it_should_pick_weighted_indices() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut counts = [0u32; 5];
+
+        for _ in 0..10_000 {
+            counts[rng.weighted_index_by(5, |i| i as f64)] += 1;
+        }
+
+        assert_eq!(counts[0], 0);
+
+        for i in 1..4 {
+            assert!(counts[i] < counts[i + 1]);
+        }
+    }
+
+    #[test]
+    fn it_should_pick_weighted_labels() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let table = [("common", 0.7), ("never", 0.0), ("rare", 0.25), ("epic", 0.05)];
+        let mut counts = [0u32; 4];
+
+        for _ in 0..10_000 {
+            let label = rng.weighted_label(&table);
+            counts[table.iter().position(|&(l, _)| l == label).unwrap()] += 1;
+        }
+
+        assert_eq!(counts[1], 0);
+
+        for (&count, &(_, p)) in counts.iter().zip(table.iter()) {
+            assert!((count as f64 / 10_000.0 - p).abs() < 0.015);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_sample_from_log_weights() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let log_weights = [-1000.0, -1001.0, -1000.0 + 2f64.ln(), f64::NEG_INFINITY];
+        let mut counts = [0u32; 4];
+
+        for _ in 0..40_000 {
+            counts[rng.sample_log_weights(&log_weights)] += 1;
+        }
+
+        // The softmax of the log-weights is [1, 1 / e, 2, 0] / (3 + 1 / e)
+        let total = 3.0 + (-1.0f64).exp();
+
+        for (i, &weight) in [1.0, (-1.0f64).exp(), 2.0].iter().enumerate() {
+            assert!((counts[i] as f64 / 40_000.0 - weight / total).abs() < 0.01);
+        }
+
+        assert_eq!(counts[3], 0);
+    }
+
+    #[test]
+    fn it_should_generate_subset_masks() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut counts = [0u32; 10];
+
+        for _ in 0..10_000 {
+            let mask = rng.random_subset_mask(10, 0.3);
+
+            assert_eq!(mask >> 10, 0);
+
+            for (i, count) in counts.iter_mut().enumerate() {
+                *count += ((mask >> i) & 1) as u32;
+            }
+        }
+
+        for &count in counts.iter() {
+            assert!(count > 2_800 && count < 3_200);
+        }
+
+        assert_eq!(rng.random_subset_mask(64, 1.0), u64::MAX);
+        assert_eq!(rng.random_subset_mask(64, 0.0), 0);
+    }
+
+    #[test]
+    fn it_should_choose_from_iterators() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut counts = [0u32; 10];
+
+        for _ in 0..10_000 {
+            counts[rng.choose_from_iter(0..10, 10).unwrap()] += 1;
+        }
+
+        for &count in counts.iter() {
+            assert!(count > 900 && count < 1_100);
+        }
+
+        assert_eq!(rng.choose_from_iter(0..10, 0), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_sample_from_count_tables() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let table = CountTable::from_counts(&[6, 0, 3, 1]);
+        let mut counts = [0u32; 4];
+
+        for _ in 0..10_000 {
+            counts[table.sample(&mut rng)] += 1;
+        }
+
+        assert_eq!(counts[1], 0);
+        assert!(counts[0] > 5_800 && counts[0] < 6_200);
+        assert!(counts[2] > 2_800 && counts[2] < 3_200);
+        assert!(counts[3] > 900 && counts[3] < 1_100);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_sample_count_tables_with_a_single_integer_draw() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+        let table = CountTable::from_counts(&[6, 0, 3, 1]);
+
+        for _ in 0..100 {
+            let expected = match b.next_below(10) {
+                0..=5 => 0,
+                6..=8 => 2,
+                _ => 3,
+            };
+
+            assert_eq!(table.sample(&mut a), expected);
+        }
+    }
+
+    #[test]
+    fn it_should_sample_arrays_without_replacement() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for _ in 0..1000 {
+            let sample: [usize; 5] = rng.sample_array(8);
+
+            for (i, &index) in sample.iter().enumerate() {
+                assert!(index < 8);
+                assert!(!sample[i + 1..].contains(&index));
+            }
+        }
+
+        let all: [usize; 10] = rng.sample_array(10);
+        let mut sorted = all;
+        sorted.sort();
+
+        assert_eq!(sorted, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.sample_array::<3>(100), b.sample_array::<3>(100));
+    }
+
+    #[test]
+    fn it_should_rotate_slices() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut counts = [0u32; 5];
+
+        for _ in 0..10_000 {
+            let mut slice = [0, 1, 2, 3, 4];
+            rng.rotate_random(&mut slice);
+
+            let offset = slice[0];
+
+            for (i, &value) in slice.iter().enumerate() {
+                assert_eq!(value, (offset + i) % 5);
+            }
+
+            counts[offset] += 1;
+        }
+
+        assert!(counts.iter().all(|&count| count > 1_900 && count < 2_100));
+
+        let mut empty: [u8; 0] = [];
+        rng.rotate_random(&mut empty);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_shuffle_indices_into_a_buffer() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut out = Vec::with_capacity(50);
+        let pointer = out.as_ptr();
+
+        rng.shuffle_indexed(50, &mut out);
+
+        let first = out.clone();
+
+        rng.shuffle_indexed(40, &mut out);
+
+        assert_eq!(out.as_ptr(), pointer);
+        assert_ne!(&first[..40], &out[..]);
+
+        out.sort();
+
+        assert_eq!(out, (0..40).collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_generate_topological_orders() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut order = rng.random_topo_order(50);
+
+        assert_ne!(order, (0..50).collect::<Vec<_>>());
+
+        order.sort();
+
+        assert_eq!(order, (0..50).collect::<Vec<_>>());
+        assert!(rng.random_topo_order(0).is_empty());
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.random_topo_order(20), b.random_topo_order(20));
+    }
+
+    #[test]
+    fn it_should_shuffle_slices() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+        let mut first: Vec<u32> = (0..20).collect();
+        let mut second = first.clone();
+
+        a.shuffle(&mut first);
+        b.shuffle(&mut second);
+
+        assert_eq!(first, second);
+        assert_ne!(first, (0..20).collect::<Vec<_>>());
+
+        first.sort();
+
+        assert_eq!(first, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn it_should_choose_weighted_elements() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let items = ["never", "rare", "common"];
+        let mut counts = [0u32; 3];
+
+        for _ in 0..10_000 {
+            let item = rng.choose_weighted(&items, &[0.0, 1.0, 3.0]);
+            counts[items.iter().position(|i| i == item).unwrap()] += 1;
+        }
+
+        assert_eq!(counts[0], 0);
+        assert!(counts[1] > 2_300 && counts[1] < 2_700);
+    }
+
+    #[test]
+    fn it_should_shuffle_decks() {
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        let deck = a.shuffled_deck();
+        let mut sorted = deck;
+        sorted.sort();
+
+        for (i, &card) in sorted.iter().enumerate() {
+            assert_eq!(card, i as u8);
+        }
+
+        assert_eq!(deck[..], b.shuffled_deck()[..]);
+        assert_ne!(deck[..], sorted[..]);
+    }
+
+    #[test]
+    fn it_should_generate_residues() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut counts = [0u32; 6];
+
+        for _ in 0..12_000 {
+            counts[rng.next_mod(6) as usize] += 1;
+        }
+
+        assert!(counts.iter().all(|&count| count > 1_850 && count < 2_150));
+
+        for &modulus in [2, 12, 97, 360, 1 << 40].iter() {
+            for _ in 0..1_000 {
+                let value = rng.next_coprime(modulus);
+
+                assert!(value < modulus);
+                assert_eq!(gcd(value, modulus), 1);
+            }
+        }
+
+        assert_eq!(rng.next_mod(1), 0);
+        assert_eq!(rng.next_coprime(1), 0);
+    }
+
+    #[test]
+    fn it_should_generate_integers_except_one() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut counts = [0u32; 5];
+
+        for _ in 0..10_000 {
+            counts[rng.next_below_except(5, 2) as usize] += 1;
+        }
+
+        assert_eq!(counts[2], 0);
+
+        for (i, &count) in counts.iter().enumerate() {
+            if i != 2 {
+                assert!(count > 2_300 && count < 2_700);
+            }
+        }
+
+        assert_eq!(rng.next_below_except(2, 0), 1);
+        assert_eq!(rng.next_below_except(2, 1), 0);
+    }
+
+    #[test]
+    fn it_should_iterate_bounded_integers() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        assert!(rng.below_iter(4).take(100).all(|value| value < 4));
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+        let values: Vec<u64> = a.below_iter(4).take(3).collect();
+
+        assert_eq!(values, [b.next_below(4), b.next_below(4), b.next_below(4)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_generate_sorted_samples() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for k in 0..20 {
+            let sample = rng.sorted_sample(20, k);
+
+            assert_eq!(sample.len(), k);
+            assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(sample.iter().all(|&index| index < 20));
+        }
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.sorted_sample(1_000_000, 10), b.sorted_sample(1_000_000, 10));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_generate_bootstrap_resamples() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let data: Vec<usize> = (0..100).collect();
+        let mut excluded = 0;
+
+        for _ in 0..1_000 {
+            let resample = rng.bootstrap_resample(&data);
+
+            assert_eq!(resample.len(), 100);
+            assert!(resample.iter().all(|&value| value < 100));
+
+            excluded += data.iter().filter(|value| !resample.contains(value)).count();
+        }
+
+        // Each element is excluded with probability 0.99^100, i.e. about 0.366
+        assert!((excluded as f64 / 100_000.0 - 0.366).abs() < 0.005);
+        assert!(rng.bootstrap_resample::<u8>(&[]).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_sample_in_original_order() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let lines: Vec<usize> = (0..100).collect();
+
+        for k in [0, 1, 10, 100].iter().cloned() {
+            let sample = rng.sample_ordered(&lines, k);
+
+            assert_eq!(sample.len(), k);
+            assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.sample_ordered(&lines, 10), b.sample_ordered(&lines, 10));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_should_not_choose_recent_elements() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let slice = [0, 1, 2, 3, 4, 5];
+        let mut recent = VecDeque::new();
+        let mut chosen = Vec::new();
+
+        for _ in 0..1000 {
+            chosen.push(*rng.choose_no_recent(&slice, &mut recent, 4));
+        }
+
+        for window in chosen.windows(5) {
+            assert!(!window[1..].contains(&window[0]));
+        }
+
+        assert!(recent.len() <= 4);
+    }
+
+    #[test]
+    fn it_should_shuffle_with_a_seed() {
+        let original: Vec<u32> = (0..20).collect();
+
+        let mut a = original.clone();
+        let mut b = original.clone();
+
+        shuffle_with_seed(&mut a, [7; 16]);
+        shuffle_with_seed(&mut b, [7; 16]);
+
+        assert_eq!(a, b);
+        assert_ne!(a, original);
+
+        a.sort();
+        assert_eq!(a, original);
+    }
+
+    #[test]
+    fn it_should_generate_subranges() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut counts = [[0u32; 4]; 4];
+
+        for _ in 0..10_000 {
+            let (start, end) = rng.next_subrange(3);
+
+            assert!(start <= end && end <= 3);
+            counts[start][end] += 1;
+        }
+
+        // There are 10 ranges within 0..3, so each should occur about 1000 times
+        for (start, row) in counts.iter().enumerate() {
+            for &count in &row[start..] {
+                assert!(count > 900 && count < 1_100);
+            }
+        }
+
+        assert_eq!(rng.next_subrange(0), (0, 0));
+    }
+}
@@ -0,0 +1,139 @@
+use super::XorShift128Plus;
+
+impl XorShift128Plus {
+    /// Returns a psuedo-random walk of `steps` steps, as the `steps + 1` positions visited
+    /// starting from 0. Each step moves either `step_size` up or `step_size` down, chosen fairly
+    /// with [`random_sign`](#method.random_sign).
+    pub fn random_walk (&mut self, steps: usize, step_size: f64) -> Vec<f64> {
+        let mut walk = Vec::with_capacity(steps + 1);
+        let mut position = 0.0;
+
+        walk.push(position);
+
+        for _ in 0..steps {
+            position += step_size * self.random_sign();
+            walk.push(position);
+        }
+
+        walk
+    }
+
+    /// Returns `count` psuedo-random bits from a two-state Markov chain, where each bit repeats
+    /// the previous one with probability `stay_prob`, and the first bit is fair. Higher values
+    /// of `stay_prob` give longer runs, with an average run length of `1 / (1 - stay_prob)`,
+    /// which is useful for testing compression and streaming code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stay_prob` is not between 0 and 1 (inclusivly).
+    pub fn markov_bits (&mut self, count: usize, stay_prob: f64) -> Vec<bool> {
+        assert!((0.0..=1.0).contains(&stay_prob), "stay_prob must be between 0 and 1");
+
+        let mut bits = Vec::with_capacity(count);
+
+        if count > 0 {
+            let mut bit = self.random_sign() > 0.0;
+            bits.push(bit);
+
+            for _ in 1..count {
+                if self.next() >= stay_prob {
+                    bit = !bit;
+                }
+
+                bits.push(bit);
+            }
+        }
+
+        bits
+    }
+
+    /// Returns `n` values of a psuedo-random AR(1) process, where each value is `phi` times the
+    /// previous one plus normally distributed noise with standard deviation `sigma`, starting
+    /// from a previous value of 0. The lag-1 autocorrelation of a long series approaches `phi`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `phi` is not strictly between -1 and 1, since the process would not be
+    /// stationary, or if `sigma` is negative.
+    pub fn ar1_series (&mut self, n: usize, phi: f64, sigma: f64) -> Vec<f64> {
+        assert!(phi.abs() < 1.0, "phi must be strictly between -1 and 1");
+        assert!(sigma >= 0.0, "sigma must not be negative");
+
+        let mut series = Vec::with_capacity(n);
+        let mut previous = 0.0;
+
+        for _ in 0..n {
+            previous = phi * previous + sigma * self.next_normal_ziggurat();
+            series.push(previous);
+        }
+
+        series
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XorShift128Plus;
+
+    #[test]
+    fn it_should_generate_random_walks() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let walk = rng.random_walk(1000, 0.5);
+
+        assert_eq!(walk.len(), 1001);
+        assert_eq!(walk[0], 0.0);
+
+        for pair in walk.windows(2) {
+            assert_eq!((pair[1] - pair[0]).abs(), 0.5);
+        }
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.random_walk(100, 1.0), b.random_walk(100, 1.0));
+    }
+
+    #[test]
+    fn it_should_generate_markov_bits() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        let runs = |bits: &[bool]| bits.windows(2).filter(|pair| pair[0] != pair[1]).count() + 1;
+
+        let fair = rng.markov_bits(10_000, 0.5);
+        let clumpy = rng.markov_bits(10_000, 0.9);
+
+        assert_eq!(fair.len(), 10_000);
+        assert_eq!(clumpy.len(), 10_000);
+
+        // The average run lengths should be about 2 and 10
+        let fair_length = 10_000.0 / runs(&fair) as f64;
+        let clumpy_length = 10_000.0 / runs(&clumpy) as f64;
+
+        assert!((fair_length - 2.0).abs() < 0.1);
+        assert!((clumpy_length - 10.0).abs() < 1.0);
+
+        assert_eq!(runs(&rng.markov_bits(100, 1.0)), 1);
+        assert_eq!(runs(&rng.markov_bits(100, 0.0)), 100);
+        assert!(rng.markov_bits(0, 0.5).is_empty());
+    }
+
+    #[test]
+    fn it_should_generate_ar1_series() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let series = rng.ar1_series(100_000, 0.7, 2.0);
+
+        assert_eq!(series.len(), 100_000);
+
+        let mean = series.iter().sum::<f64>() / series.len() as f64;
+        let variance = series.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>();
+        let covariance = series.windows(2).map(|pair| (pair[0] - mean) * (pair[1] - mean)).sum::<f64>();
+
+        assert!((covariance / variance - 0.7).abs() < 0.01);
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_reject_non_stationary_ar1_series() {
+        XorShift128Plus::from_u32(4293262078).ar1_series(10, 1.0, 1.0);
+    }
+}
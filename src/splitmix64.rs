@@ -0,0 +1,81 @@
+use super::{splitmix64, SPLITMIX64_GAMMA};
+
+/// The splitmix64 output function used for seeding, as a standalone stream. It's fast and has a
+/// period of 2^64, but its main use here is stretching a single `u64` into a full 128-bit seed.
+/// Note that [`XorShift128Plus::from_u64`](struct.XorShift128Plus.html#method.from_u64) applies
+/// the output function twice in a row rather than stepping this stream, so seeding from
+/// [`next_seed`](#method.next_seed) gives a different generator than `from_u64` with the same
+/// seed.
+///
+/// # Examples
+///
+/// ```
+/// extern crate xorshift128plus;
+///
+/// use xorshift128plus::{SplitMix64, XorShift128Plus};
+///
+/// # fn main() {
+/// let mut seeder = SplitMix64::new(42);
+/// let mut rng = XorShift128Plus::from_bytes(seeder.next_seed());
+///
+/// println!("First random float: {}", rng.next());
+/// # }
+/// ```
+pub struct SplitMix64 (u64);
+
+impl SplitMix64 {
+    /// Constructs a new generator from `seed`. Every seed, including 0, is valid.
+    pub fn new (seed: u64) -> SplitMix64 {
+        SplitMix64 (seed)
+    }
+
+    /// Returns the next psuedo-random unsigned 64bit integer.
+    pub fn next_u64 (&mut self) -> u64 {
+        let result = splitmix64(self.0);
+        self.0 = self.0.wrapping_add(SPLITMIX64_GAMMA);
+        result
+    }
+
+    /// Returns 16 bytes of psuedo-random data, made from the next two outputs, suitable for
+    /// passing to [`XorShift128Plus::from_bytes`](struct.XorShift128Plus.html#method.from_bytes).
+    pub fn next_seed (&mut self) -> [u8; 16] {
+        let mut seed = [0u8; 16];
+
+        seed[0..8].copy_from_slice(&self.next_u64().to_le_bytes());
+        seed[8..16].copy_from_slice(&self.next_u64().to_le_bytes());
+
+        seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SplitMix64;
+
+    #[test]
+    fn it_should_generate_the_reference_sequence() {
+        let mut rng = SplitMix64::new(0);
+
+        assert_eq!(rng.next_u64(), 0xe220a8397b1dcdaf);
+        assert_eq!(rng.next_u64(), 0x6e789e6aa1b965f4);
+        assert_eq!(rng.next_u64(), 0x06c45d188009454f);
+        assert_eq!(rng.next_u64(), 0xf88bb8a8724c81ec);
+
+        let mut rng = SplitMix64::new(1234567);
+
+        assert_eq!(rng.next_u64(), 6457827717110365317);
+        assert_eq!(rng.next_u64(), 3203168211198807973);
+        assert_eq!(rng.next_u64(), 9817491932198370423);
+    }
+
+    #[test]
+    fn it_should_generate_seeds_from_consecutive_outputs() {
+        let mut a = SplitMix64::new(1234567);
+        let mut b = SplitMix64::new(1234567);
+
+        let seed = a.next_seed();
+
+        assert_eq!(seed[0..8], b.next_u64().to_le_bytes());
+        assert_eq!(seed[8..16], b.next_u64().to_le_bytes());
+    }
+}
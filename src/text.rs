@@ -0,0 +1,251 @@
+use super::XorShift128Plus;
+
+const HEX_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+const CONSONANTS: &[u8; 20] = b"bcdfghjklmnpqrstvwxz";
+const VOWELS: &[u8; 6] = b"aeiouy";
+const SURROGATE_START: u32 = 0xd800;
+const SURROGATE_COUNT: u32 = 0x800;
+const SCALAR_VALUE_COUNT: u32 = 0x110000 - SURROGATE_COUNT;
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const ALNUM_ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// A class of characters for
+/// [`XorShift128Plus::string_from_classes`](struct.XorShift128Plus.html#method.string_from_classes),
+/// similar to a character class in a regular expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CharClass {
+    /// An ASCII digit, `0-9`.
+    Digit,
+    /// A lowercase ASCII letter, `a-z`.
+    Lower,
+    /// An uppercase ASCII letter, `A-Z`.
+    Upper,
+    /// An ASCII digit or letter, `0-9A-Za-z`.
+    Alnum,
+    /// Any of the given characters.
+    Custom(Vec<char>),
+}
+
+impl XorShift128Plus {
+    fn random_bytes (&mut self, len: usize) -> Vec<u8> {
+        let mut bytes = vec![0u8; len];
+        self.fill_bytes(&mut bytes);
+        bytes
+    }
+
+    /// Returns `bytes` psuedo-random bytes encoded as lowercase hexadecimal, i.e. a string of
+    /// `2 * bytes` characters. Note that this is not suitable for secret tokens.
+    pub fn hex_string (&mut self, bytes: usize) -> String {
+        let mut result = String::with_capacity(2 * bytes);
+
+        for byte in self.random_bytes(bytes) {
+            result.push(HEX_ALPHABET[(byte >> 4) as usize] as char);
+            result.push(HEX_ALPHABET[(byte & 0x0f) as usize] as char);
+        }
+
+        result
+    }
+
+    /// Returns `bytes` psuedo-random bytes encoded as standard, padded base64. Note that this is
+    /// not suitable for secret tokens.
+    pub fn base64_string (&mut self, bytes: usize) -> String {
+        let mut result = String::with_capacity(bytes.div_ceil(3) * 4);
+
+        for chunk in self.random_bytes(bytes).chunks(3) {
+            let b0 = chunk[0] as usize;
+            let b1 = chunk.get(1).cloned().unwrap_or(0) as usize;
+            let b2 = chunk.get(2).cloned().unwrap_or(0) as usize;
+
+            result.push(BASE64_ALPHABET[b0 >> 2] as char);
+            result.push(BASE64_ALPHABET[((b0 & 0x03) << 4) | (b1 >> 4)] as char);
+
+            if chunk.len() > 1 {
+                result.push(BASE64_ALPHABET[((b1 & 0x0f) << 2) | (b2 >> 6)] as char);
+            } else {
+                result.push('=');
+            }
+
+            if chunk.len() > 2 {
+                result.push(BASE64_ALPHABET[b2 & 0x3f] as char);
+            } else {
+                result.push('=');
+            }
+        }
+
+        result
+    }
+
+    /// Returns a psuedo-random, pronounceable lowercase token such as "bomika", made of
+    /// `syllables` syllables that each are a consonant followed by a vowel. Thus the token is
+    /// always `2 * syllables` characters long.
+    pub fn pronounceable (&mut self, syllables: usize) -> String {
+        let mut result = String::with_capacity(2 * syllables);
+
+        for _ in 0..syllables {
+            result.push(CONSONANTS[self.next_below(CONSONANTS.len() as u64) as usize] as char);
+            result.push(VOWELS[self.next_below(VOWELS.len() as u64) as usize] as char);
+        }
+
+        result
+    }
+
+    /// Returns a psuedo-random `char`, chosen uniformly among all Unicode scalar values (i.e.
+    /// every code point except the surrogates). Note that most of them are outside the Basic
+    /// Multilingual Plane, and thus four bytes long in UTF-8.
+    pub fn gen_char (&mut self) -> char {
+        let mut value = self.next_below(SCALAR_VALUE_COUNT as u64) as u32;
+
+        if value >= SURROGATE_START {
+            value += SURROGATE_COUNT;
+        }
+
+        std::char::from_u32(value).unwrap()
+    }
+
+    /// Returns a psuedo-random string of `len_per_class` characters from each class of
+    /// `pattern` in turn, e.g. for fuzzing validators. A pattern of `[Digit, Lower]` with a
+    /// length of 3 is thus like matching the regular expression `[0-9]{3}[a-z]{3}`. Each
+    /// character is chosen uniformly within its class.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `Custom` class is empty.
+    pub fn string_from_classes (&mut self, pattern: &[CharClass], len_per_class: usize) -> String {
+        let mut result = String::with_capacity(pattern.len() * len_per_class);
+
+        for class in pattern {
+            for _ in 0..len_per_class {
+                let c = match *class {
+                    CharClass::Digit => ALNUM_ALPHABET[self.next_below(10) as usize] as char,
+                    CharClass::Upper => ALNUM_ALPHABET[10 + self.next_below(26) as usize] as char,
+                    CharClass::Lower => ALNUM_ALPHABET[36 + self.next_below(26) as usize] as char,
+                    CharClass::Alnum => ALNUM_ALPHABET[self.next_below(62) as usize] as char,
+                    CharClass::Custom(ref chars) => {
+                        assert!(!chars.is_empty(), "custom classes must not be empty");
+                        chars[self.next_below(chars.len() as u64) as usize]
+                    }
+                };
+
+                result.push(c);
+            }
+        }
+
+        result
+    }
+
+    /// Returns a psuedo-random string of at most `max_bytes` bytes. Characters from
+    /// [`gen_char`](#method.gen_char) are appended until the next one wouldn't fit, so the
+    /// string may end up to three bytes shorter than `max_bytes`.
+    pub fn utf8_string (&mut self, max_bytes: usize) -> String {
+        let mut result = String::with_capacity(max_bytes);
+
+        loop {
+            let c = self.gen_char();
+
+            if result.len() + c.len_utf8() > max_bytes {
+                return result;
+            }
+
+            result.push(c);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CharClass, XorShift128Plus};
+
+    #[test]
+    fn it_should_generate_hex_strings() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for bytes in 0..40 {
+            let hex = rng.hex_string(bytes);
+
+            assert_eq!(hex.len(), 2 * bytes);
+            assert!(hex.chars().all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)));
+        }
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.hex_string(16), b.hex_string(16));
+    }
+
+    #[test]
+    fn it_should_generate_base64_strings() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for bytes in 0..40 {
+            let base64 = rng.base64_string(bytes);
+
+            assert_eq!(base64.len(), bytes.div_ceil(3) * 4);
+            assert!(base64.trim_end_matches('=').bytes().all(|c| c.is_ascii_alphanumeric() || c == b'+' || c == b'/'));
+        }
+    }
+
+    #[test]
+    fn it_should_generate_utf8_strings() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for max_bytes in 0..100 {
+            let string = rng.utf8_string(max_bytes);
+
+            assert!(string.len() <= max_bytes);
+            assert!(String::from_utf8(string.clone().into_bytes()).is_ok());
+        }
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.utf8_string(64), b.utf8_string(64));
+    }
+
+    #[test]
+    fn it_should_generate_pronounceable_tokens() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for syllables in 0..10 {
+            let token = rng.pronounceable(syllables);
+
+            assert_eq!(token.len(), 2 * syllables);
+            assert!(token.bytes().all(|c| c.is_ascii_lowercase()));
+
+            for (i, c) in token.bytes().enumerate() {
+                assert_eq!(b"aeiouy".contains(&c), i % 2 == 1);
+            }
+        }
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.pronounceable(3), b.pronounceable(3));
+    }
+
+    #[test]
+    fn it_should_generate_strings_from_classes() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for _ in 0..100 {
+            let string = rng.string_from_classes(&[CharClass::Digit, CharClass::Lower], 3);
+
+            assert_eq!(string.len(), 6);
+            assert!(string[..3].bytes().all(|c| c.is_ascii_digit()));
+            assert!(string[3..].bytes().all(|c| c.is_ascii_lowercase()));
+        }
+
+        let pattern = [CharClass::Upper, CharClass::Alnum, CharClass::Custom(vec!['-', 'é'])];
+        let string = rng.string_from_classes(&pattern, 5);
+        let chars: Vec<char> = string.chars().collect();
+
+        assert_eq!(chars.len(), 15);
+        assert!(chars[..5].iter().all(|c| c.is_ascii_uppercase()));
+        assert!(chars[5..10].iter().all(|c| c.is_ascii_alphanumeric()));
+        assert!(chars[10..].iter().all(|&c| c == '-' || c == 'é'));
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        assert_eq!(a.string_from_classes(&pattern, 8), b.string_from_classes(&pattern, 8));
+    }
+}
@@ -0,0 +1,63 @@
+use core::time::Duration;
+
+use super::XorShift128Plus;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+impl XorShift128Plus {
+    /// Returns a psuedo-random duration between `min` (inclusivly) and `max` (exclusivly), by
+    /// interpolating between their nanosecond counts. Note that the interpolation uses a single
+    /// float draw, so spans longer than 2^52 nanoseconds (about 52 days) won't reach every
+    /// nanosecond in between.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is not less than `max`.
+    pub fn next_duration (&mut self, min: Duration, max: Duration) -> Duration {
+        assert!(min < max, "min must be less than max");
+
+        let min = min.as_nanos();
+        let span = max.as_nanos() - min;
+        let offset = ((span as f64) * self.next()) as u128;
+
+        // Rounding `span` to a float may nudge the product up to `span` itself
+        let nanos = min + offset.min(span - 1);
+
+        Duration::new((nanos / NANOS_PER_SEC) as u64, (nanos % NANOS_PER_SEC) as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::XorShift128Plus;
+
+    #[test]
+    fn it_should_generate_durations_in_range() {
+        let min = Duration::from_millis(10);
+        let max = Duration::from_millis(250);
+
+        let mut a = XorShift128Plus::from_u32(4293262078);
+        let mut b = XorShift128Plus::from_u32(4293262078);
+
+        for _ in 0..1000 {
+            let duration = a.next_duration(min, max);
+
+            assert!(duration >= min && duration < max);
+            assert_eq!(duration, b.next_duration(min, max));
+        }
+    }
+
+    #[test]
+    fn it_should_generate_durations_wider_than_u64_nanos() {
+        let min = Duration::from_secs(0);
+        let max = Duration::from_secs(u64::MAX);
+
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+
+        for _ in 0..1000 {
+            assert!(rng.next_duration(min, max) < max);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use super::XorShift128Plus;
+
+/// Types with a fixed number of values that can be picked uniformly with
+/// [`XorShift128Plus::gen_variant`](struct.XorShift128Plus.html#method.gen_variant), such as
+/// fieldless enums.
+///
+/// # Examples
+///
+/// For a fieldless enum, `COUNT` is the number of variants, and `from_index` maps each index
+/// below it to one variant:
+///
+/// ```
+/// extern crate xorshift128plus;
+///
+/// use xorshift128plus::{RandomVariant, XorShift128Plus};
+///
+/// enum Direction { North, East, South, West }
+///
+/// impl RandomVariant for Direction {
+///     const COUNT: usize = 4;
+///
+///     fn from_index (i: usize) -> Direction {
+///         match i {
+///             0 => Direction::North,
+///             1 => Direction::East,
+///             2 => Direction::South,
+///             _ => Direction::West,
+///         }
+///     }
+/// }
+///
+/// # fn main() {
+/// let mut rng = XorShift128Plus::from_u32(4293262078);
+/// let direction: Direction = rng.gen_variant();
+/// # }
+/// ```
+pub trait RandomVariant: Sized {
+    /// The number of distinct values, which must be greater than 0.
+    const COUNT: usize;
+
+    /// Returns the value for index `i`, which is always less than `COUNT`.
+    fn from_index (i: usize) -> Self;
+}
+
+impl XorShift128Plus {
+    /// Returns one of the `T::COUNT` values of `T`, chosen uniformly with an unbiased integer
+    /// draw.
+    pub fn gen_variant<T: RandomVariant> (&mut self) -> T {
+        T::from_index(self.next_below(T::COUNT as u64) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RandomVariant, XorShift128Plus};
+
+    enum Color { Red, Green, Blue }
+
+    impl RandomVariant for Color {
+        const COUNT: usize = 3;
+
+        fn from_index (i: usize) -> Color {
+            match i {
+                0 => Color::Red,
+                1 => Color::Green,
+                _ => Color::Blue,
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_generate_variants_uniformly() {
+        let mut rng = XorShift128Plus::from_u32(4293262078);
+        let mut counts = [0u32; 3];
+
+        for _ in 0..9_000 {
+            counts[rng.gen_variant::<Color>() as usize] += 1;
+        }
+
+        for &count in counts.iter() {
+            assert!(count > 2_800 && count < 3_200);
+        }
+    }
+}
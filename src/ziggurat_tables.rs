@@ -0,0 +1,142 @@
+// Tables for the 256 layer ziggurat of the standard normal distribution, as described by
+// G. Marsaglia and W. W. Tsang in "The Ziggurat Method for Generating Random Variables". They
+// were generated from `R` (the start of the tail) with `X[i + 1] = f^-1(V / X[i] + f(X[i]))`,
+// where `f(x) = e^(-x^2 / 2)` and `V` is the area of each layer, and `F[i] = f(X[i])`.
+
+pub const ZIG_NORM_R: f64 = 3.654152885361009;
+
+pub const ZIG_NORM_X: [f64; 257] = [
+    3.9107579595249167, 3.654152885361009, 3.449278298561431, 3.320244733839825,
+    3.224575052047801, 3.147889289518, 3.0835261320021425, 3.027837791769593,
+    2.9786032798818427, 2.934366867208887, 2.894121053613412, 2.857138730873224,
+    2.8228773968264425, 2.790921174001927, 2.7609440052799856, 2.732685359044011,
+    2.7059336561230616, 2.6805146432857443, 2.6562830375767423, 2.633116393631582,
+    2.6109105184888226, 2.5895759867082857, 2.5690354526818426, 2.549221550324782,
+    2.5300752321598527, 2.5115444416266928, 2.4935830412710454, 2.4761499396705218,
+    2.4592083743347035, 2.4427253182003628, 2.4266709849371453, 2.411018413901118,
+    2.395743119781926, 2.3808227951720844, 2.36623705671729, 2.351967227379144,
+    2.337996148796528, 2.324308018871132, 2.3108882506013715, 2.297723348902863,
+    2.2848008027244915, 2.2721089902283813, 2.259637095173787, 2.2473750329473887,
+    2.2353133849299205, 2.2234433400925098, 2.21175664288416, 2.2002455466112756,
+    2.18890277162636, 2.177721467740292, 2.1666951803543073, 2.155817819876736,
+    2.1450836340478876, 2.1344871828460157, 2.1240233156895223, 2.1136871506866517,
+    2.1034740557148757, 2.09337963113879, 2.083399693998303, 2.0735302635187414,
+    2.0637675478117306, 2.0541079316506505, 2.04454796521753, 2.0350843537296175,
+    2.025713947863853, 2.0164337349062027, 2.0072408305605274, 1.9981324713584183,
+    1.9891060076174367, 1.9801588969004753, 1.971288697933658, 1.9624930649443617,
+    1.9537697423846454, 1.9451165600086768, 1.9365314282756931, 1.928012334052664,
+    1.9195573365931864, 1.9111645637712515, 1.9028322085504275, 1.894558525670703,
+    1.886341828536781, 1.878180486292994, 1.870072921071265, 1.8620176053996724,
+    1.8540130597602003, 1.8460578502851839, 1.838150586582805, 1.8302899196827553,
+    1.8224745400938844, 1.8147031759662813, 1.8069745913508195, 1.7992875845497187,
+    1.791640986552161, 1.78403365954944, 1.7764644955245215, 1.7689324149112673,
+    1.7614363653189091, 1.7539753203176704, 1.7465482782817214, 1.7391542612859108,
+    1.7317923140529623, 1.7244615029480441, 1.7171609150178224, 1.7098896570713011,
+    1.7026468547999223, 1.6954316519345607, 1.6882432094371944, 1.681080704725173,
+    1.6739433309261242, 1.6668302961616648, 1.6597408228581818, 1.6526741470830553,
+    1.6456295179047817, 1.638606196775547, 1.6316034569348727, 1.624620582833034,
+    1.617656869573015, 1.6107116223698297, 1.6037841560260941, 1.5968737944227878,
+    1.5899798700241905, 1.583101723396029, 1.576238702735906, 1.5693901634151233,
+    1.5625554675310445, 1.5557339834691761, 1.5489250854741732, 1.5421281532290017,
+    1.5353425714415139, 1.528567729437712, 1.5218030207609978, 1.5150478427767144,
+    1.5083015962813113, 1.5015636851154637, 1.4948335157804935, 1.4881104970574472,
+    1.481394039628187, 1.4746835556978553, 1.4679784586180793, 1.4612781625102753,
+    1.45458208188841, 1.4478896312805758, 1.4412002248487237, 1.4345132760058918,
+    1.4278281970302555, 1.4211443986753085, 1.4144612897754707, 1.4077782768463982,
+    1.4010947636792503, 1.3944101509281404, 1.3877238356899755, 1.3810352110758548,
+    1.3743436657731656, 1.3676485835974754, 1.3609493430332822, 1.354245316762634,
+    1.3475358711805863, 1.340820365896403, 1.334098153219359, 1.3273685776279247,
+    1.3206309752210552, 1.3138846731502194, 1.30712898903073, 1.3003632303308361,
+    1.2935866937369467, 1.2867986644932425, 1.279998415713817, 1.2731852076653554,
+    1.2663582870182284, 1.2595168860637131, 1.2526602218948961, 1.2457874955486261,
+    1.2388978911056863, 1.231990574746135, 1.2250646937565297, 1.2181193754854807,
+    1.2111537262436982, 1.2041668301443804, 1.1971577478794404, 1.190125515426691,
+    1.1830691426826856, 1.1759876120154509, 1.1688798767308322, 1.1617448594456106,
+    1.1545814503599268, 1.1473885054208481, 1.1401648443681505, 1.132909248652533,
+    1.1256204592155323, 1.1182971741193437, 1.1109380460135743, 1.1035416794246382,
+    1.09610662785202, 1.0886313906539782, 1.0811144097034022, 1.0735540657924345,
+    1.0659486747621207, 1.0582964833306734, 1.0505956645909282, 1.0428443131441474,
+    1.0350404398334394, 1.0271819660356445, 1.019266717465483, 1.0112924174399947,
+    1.003256679544672, 0.9951569996350901, 0.9869907470990615, 0.9787551552942237,
+    0.9704473110642236, 0.9620641432230397, 0.9536024098810852, 0.9450586844681645,
+    0.9364293402865742, 0.9277105334019992, 0.9188981836495896, 0.9099879534967176,
+    0.9009752244612208, 0.8918550707329405, 0.8826222295851646, 0.8732710680888597,
+    0.8637955455533078, 0.8541891710081628, 0.844444954909153, 0.834555354086381,
+    0.8245122087522911, 0.8143066701352142, 0.8039291169899702, 0.7933690588406223,
+    0.782615023307232, 0.7716544242245669, 0.7604734064301069, 0.7490566620178141,
+    0.7373872114342944, 0.7254461409099985, 0.7132122851909748, 0.7006618411068138,
+    0.6877678927957872, 0.6744998228372925, 0.6608225742444183, 0.6466957148949922,
+    0.6320722363860595, 0.6168969900077496, 0.6011046177559908, 0.5846167661063775,
+    0.5673382570538168, 0.549151702327163, 0.529909720661556, 0.5094233296020896,
+    0.4874439661392335, 0.4636343367908794, 0.4375184022078686, 0.40838913461198767,
+    0.3751213328783766, 0.33573751921442047, 0.2861745917920662, 0.21524189598487156,
+    0.0,
+];
+
+pub const ZIG_NORM_F: [f64; 257] = [
+    0.0004774677646093862, 0.001260285930498598, 0.002609072746102164, 0.0040379725933630374,
+    0.005522403299251011, 0.0070508754713732415, 0.008616582769398749, 0.010214971439701487,
+    0.01184275785790791, 0.01349745060173989, 0.015177088307935337, 0.016880083152543187,
+    0.01860512127572467, 0.020351096230044538, 0.0221170627073089, 0.02390220330579591,
+    0.025705804008548945, 0.027527235669603148, 0.029365939758133387, 0.031221417191920328,
+    0.03309321945857862, 0.034980941461716174, 0.0368842156885674, 0.03880270740452624,
+    0.040736110655941085, 0.04268414491647461, 0.0446465522512946, 0.04662309490193053,
+    0.048613553215868695, 0.05061772386094794, 0.05263541827679238, 0.054666461324889094,
+    0.05671069010620308, 0.058767952920933925, 0.06083810834954002, 0.06292102443775822,
+    0.06501657797124295, 0.06712465382778857, 0.06924514439700682, 0.07137794905889047,
+    0.07352297371398138, 0.07568013035892718, 0.07784933670209612, 0.08003051581466315,
+    0.08222359581320299, 0.08442850957035354, 0.08664519445055814, 0.08887359206827597,
+    0.09111364806637383, 0.0933653119126911, 0.09562853671300908, 0.09790327903886259,
+    0.1001894987688101, 0.10248715894193534, 0.10479622562248721, 0.107116667774684,
+    0.10944845714681205, 0.11179156816383844, 0.11414597782783878, 0.11651166562561123,
+    0.11888861344291038, 0.12127680548479063, 0.1236762282015969, 0.12608687022018628,
+    0.1285087222799999, 0.13094177717364472, 0.13338602969166952, 0.13584147657125412,
+    0.1383081164485511, 0.14078594981444506, 0.14327497897351382, 0.14577520800599442,
+    0.14828664273257494, 0.15080929068184615, 0.1533431610602633, 0.15588826472447975,
+    0.15844461415592484, 0.16101222343751165, 0.16359110823236628, 0.16618128576448263,
+    0.1687827748012121, 0.1713955956375065, 0.17401977008183936, 0.17665532144373555,
+    0.17930227452284822, 0.18196065559952312, 0.18463049242679985, 0.1873118142238008,
+    0.19000465167046546, 0.19270903690358965, 0.1954250035141348, 0.19815258654577567,
+    0.20089182249465717, 0.20364274931033544, 0.20640540639788124, 0.2091798346211255,
+    0.2119660763070306, 0.214764175251174, 0.21757417672433152, 0.22039612748015233,
+    0.22323007576391782, 0.22607607132238053, 0.22893416541468053, 0.2318044108243389,
+    0.23468686187233026, 0.23758157443123834, 0.24048860594050084, 0.24340801542275048,
+    0.246339863501264, 0.24928421241852858, 0.25224112605594223, 0.25521066995466196,
+    0.25819291133761924, 0.2611879191327212, 0.2641957639972612, 0.26721651834356147,
+    0.27025025636587546, 0.27329705406857707, 0.2763569892956683, 0.27943014176163794,
+    0.2825165930837076, 0.28561642681550176, 0.2887297284821829, 0.2918565856170952,
+    0.2949970877999618, 0.2981513266966855, 0.30131939610080305, 0.30450139197665,
+    0.30769741250429206, 0.3109075581262865, 0.3141319315963372, 0.3173706380299136,
+    0.32062378495690536, 0.3238914823763911, 0.3271738428136014, 0.3304709813791636,
+    0.33378301583071845, 0.33711006663700605, 0.34045225704452187, 0.3438097131468507,
+    0.34718256395679364, 0.3505709414814061, 0.3539749808000768, 0.3573948201457805,
+    0.36083060098964803, 0.36428246812900406, 0.3677505697790326, 0.37123505766823955,
+    0.37473608713789125, 0.3782538172456193, 0.38178841087339377, 0.38534003484007745,
+    0.38890886001878894, 0.39249506145931584, 0.39609881851583273, 0.39972031498019756,
+    0.40335973922111484, 0.40701728432947376, 0.41069314827018866, 0.4143875340408916,
+    0.4181006498378486, 0.42183270922949634, 0.4255839313380224, 0.4293545410294419,
+    0.43314476911265276, 0.436954852547986, 0.4407850346658044, 0.4446355653957398,
+    0.4485067015072034, 0.45239870686184896, 0.45631185267871677, 0.4602464178128432,
+    0.46420268904817463, 0.4681809614056939, 0.4721815384677304, 0.47620473271950614,
+    0.48025086590904703, 0.4843202694266836, 0.4884132847054583, 0.4925302636438688,
+    0.4966715690524901, 0.5008375751261491, 0.5050286679434685, 0.5092452459957482,
+    0.5134877207473272, 0.5177565172297565, 0.522052074672322, 0.5263748471716846,
+    0.5307253044036623, 0.535103932380458, 0.5395112342569526, 0.5439477311900267,
+    0.5484139632552664, 0.552910490425833, 0.5574378936187666, 0.5619967758145251,
+    0.566587763256165, 0.5712115067352538, 0.5758686829723543, 0.5805599961007915,
+    0.5852861792633718, 0.5900479963328262, 0.5948462437679877, 0.5996817526191256,
+    0.604555390697468, 0.6094680649257737, 0.6144207238889141, 0.6194143606058345,
+    0.6244500155470267, 0.6295287799248369, 0.6346517992876238, 0.6398202774530568,
+    0.6450354808208226, 0.650298743110817, 0.6556114705796976, 0.6609751477766634,
+    0.6663913439087504, 0.6718617198970824, 0.6773880362187737, 0.6829721616449951,
+    0.688616083004672, 0.6943219161261169, 0.7000919181365118, 0.7059285013327545,
+    0.7118342488782486, 0.7178119326307222, 0.7238645334686304, 0.7299952645614765,
+    0.736207598126863, 0.7425052963401514, 0.7488924472191572, 0.7553735065070964,
+    0.7619533468367955, 0.7686373157984865, 0.7754313049811874, 0.7823418326548027,
+    0.7893761435660249, 0.7965423304229593, 0.8038494831709647, 0.8113078743126567,
+    0.8189291916037029, 0.826726833946222, 0.8347162929868841, 0.8429156531122048,
+    0.8513462584586786, 0.8600336211963322, 0.8690086880368577, 0.8783096558089181,
+    0.8879846607558342, 0.8980959218983443, 0.9087264400521318, 0.919991505039348,
+    0.9320600759592316, 0.9451989534423009, 0.9598790918001081, 0.9771017012676734,
+    1.0,
+];